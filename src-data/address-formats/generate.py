@@ -0,0 +1,61 @@
+import csv
+import json
+import re
+import sys
+
+FIELDS = {
+    'N': 'recipient',
+    'O': 'organization',
+    'A': 'street_address',
+    'D': 'dependent_locality',
+    'C': 'locality',
+    'S': 'administrative_area',
+    'Z': 'postal_code',
+    'X': 'sorting_code'
+}
+
+DEFAULT_CODE = 'ZZ'
+
+def read_countries():
+    with open('../iso-3166/all.csv', encoding='utf-8', newline='') as csv_file:
+        return set(row['alpha_2'] for row in csv.DictReader(csv_file))
+
+def to_fields(code, letters):
+    for letter in letters:
+        if letter not in FIELDS:
+            raise ValueError('unknown field for %s: %s' % (code, letter))
+    return [FIELDS[letter] for letter in letters]
+
+def read_data():
+    countries = read_countries()
+    formats = {}
+    with open('address-formats.tsv', encoding='utf-8', newline='') as tsv_file:
+        for row in csv.DictReader(tsv_file, delimiter='\t', quoting=csv.QUOTE_NONE):
+            code = row['alpha_2']
+            if code != DEFAULT_CODE and code not in countries:
+                raise ValueError('unknown country: %s' % code)
+            to_fields(code, re.findall(r'%([^n])', row['format']))
+            to_fields(code, re.findall(r'%([^n])', row['latin_format']))
+            formats[code] = {
+                'country_code': code,
+                'format': row['format'],
+                'latin_format': row['latin_format'] or None,
+                'required': to_fields(code, row['require']),
+                'upper': to_fields(code, row['upper']),
+                'administrative_area_type': row['administrative_area'],
+                'locality_type': row['locality'],
+                'dependent_locality_type': row['dependent_locality'],
+                'postal_code_type': row['postal_code'],
+                'subdivision_style': row['subdivision_style']
+            }
+    return formats
+
+def write_data(formats, out_path):
+    print('writing %s/address_formats.json' % out_path)
+    with open('%s/address_formats.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(formats, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
@@ -0,0 +1,49 @@
+import csv
+import json
+import sys
+
+def read_systems():
+    systems = {}
+    with open('measurement-systems.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            systems[row['alpha_2']] = {
+                'country_code': row['alpha_2'],
+                'system': row['system'],
+                'temperature_system': row['temperature'],
+                'paper_size': row['paper_size']
+            }
+    return systems
+
+def read_patterns():
+    patterns = {}
+    with open('number-symbols.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            patterns[row['language']] = {
+                'decimal': row['decimal'],
+                'group': row['group'],
+                'minimum_grouping': int(row['minimum_grouping']),
+                'plural': row['plural'],
+                'units': {}
+            }
+    with open('unit-patterns.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            patterns[row['language']]['units'][row['unit']] = {
+                'short': row['short'],
+                'long_one': row['long_one'],
+                'long_other': row['long_other']
+            }
+    return patterns
+
+def write_json(value, file_name, out_path):
+    print('writing %s/%s' % (out_path, file_name))
+    with open('%s/%s' % (out_path, file_name), 'w', encoding='utf-8') as text_file:
+        print(json.dumps(value, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+def write_data(systems, patterns, out_path):
+    write_json(systems, 'measurement_systems.json', out_path)
+    write_json(patterns, 'unit_patterns.json', out_path)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_systems(), read_patterns(), sys.argv[1])
@@ -0,0 +1,31 @@
+import csv
+import json
+import sys
+
+def read_data():
+    territories = {}
+    with open('territory-languages.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            territory = territories.setdefault(row['alpha_2'], {
+                'country_code': row['alpha_2'],
+                'population': int(row['population']),
+                'languages': []
+            })
+            territory['languages'].append({
+                'language_code': row['language'],
+                'population_percent': float(row['population_percent']),
+                'status': row['status']
+            })
+    for territory in territories.values():
+        territory['languages'].sort(key=lambda l: -l['population_percent'])
+    return territories
+
+def write_data(territories, out_path):
+    print('writing %s/territories.json' % out_path)
+    with open('%s/territories.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(territories, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
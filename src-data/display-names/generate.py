@@ -0,0 +1,132 @@
+import json
+import re
+import sys
+
+LOCALES = ['ar', 'de', 'es', 'fr', 'it', 'ja', 'ko', 'nl', 'pl', 'pt', 'pt_PT', 'ru', 'sv',
+           'tr', 'uk', 'zh', 'zh_Hant']
+
+PARENTS = {'pt_PT': 'pt'}
+
+FORMS = [('standard', ''), ('short', '%short'), ('variant', '%variant')]
+
+TOKEN = re.compile(r'\s*(?:(//[^\n]*)|("(?:[^"\\]|\\.)*")|([{},])|([^\s{},"]+))')
+
+def tokenize(text):
+    position = 0
+    text = text.rstrip()
+    while position < len(text):
+        match = TOKEN.match(text, position)
+        if match is None:
+            raise ValueError('unexpected text at %d' % position)
+        position = match.end()
+        (comment, string, punctuation, key) = match.groups()
+        if string is not None:
+            yield ('string', json.loads(string))
+        elif punctuation is not None:
+            yield (punctuation, punctuation)
+        elif key is not None:
+            yield ('key', key)
+
+def parse_resource(tokens):
+    # a resource is either a table of named resources, or an array of
+    # strings and unnamed resources
+    table = {}
+    array = []
+    for (kind, value) in tokens:
+        if kind == '}':
+            return table if table else (array[0] if len(array) == 1 else array)
+        elif kind == 'key':
+            next(tokens)
+            table[value] = parse_resource(tokens)
+        elif kind == 'string':
+            array.append(value)
+        elif kind == '{':
+            array.append(parse_resource(tokens))
+    raise ValueError('unexpected end of resource')
+
+def read_bundle(locale):
+    with open('cldr/%s.txt' % locale, encoding='utf-8') as txt_file:
+        tokens = tokenize(txt_file.read())
+        next(tokens)
+        next(tokens)
+        bundle = parse_resource(tokens)
+    if locale in PARENTS:
+        parent = read_bundle(PARENTS[locale])
+        for (table, names) in bundle.items():
+            parent.setdefault(table, {}).update(names)
+        bundle = parent
+    return bundle
+
+def read_iso_data(file_name, key):
+    with open(file_name, encoding='utf-8') as json_file:
+        return json.load(json_file)[key]
+
+def localized_forms(bundles, table, cldr_code, english_name):
+    names = {}
+    for (locale, bundle) in bundles.items():
+        forms = {}
+        for (form, suffix) in FORMS:
+            name = bundle.get(table + suffix, {}).get(cldr_code)
+            if name is not None:
+                forms[form] = name
+        if 'standard' not in forms:
+            if locale != 'en':
+                continue
+            forms['standard'] = english_name
+        if locale == 'en' or forms != names['en']:
+            names[locale] = forms
+    return names
+
+def read_countries(bundles):
+    countries = {}
+    for row in read_iso_data('../iso-3166/iso_3166-1.json', '3166-1'):
+        countries[row['alpha_3']] = localized_forms(
+            bundles, 'Countries', row['alpha_2'], row['name'])
+    return countries
+
+def read_languages(bundles):
+    languages = {}
+    for row in read_iso_data('iso_639-3.json', '639-3'):
+        cldr_code = row.get('alpha_2', row['alpha_3'])
+        languages[row['alpha_3']] = localized_forms(bundles, 'Languages', cldr_code, row['name'])
+    return languages
+
+def read_scripts(bundles):
+    scripts = {}
+    for row in read_iso_data('iso_15924.json', '15924'):
+        scripts[row['alpha_4']] = localized_forms(bundles, 'Scripts', row['alpha_4'], row['name'])
+    return scripts
+
+def read_currencies(bundles):
+    currencies = {}
+    for row in read_iso_data('iso_4217.json', '4217'):
+        names = {}
+        for (locale, bundle) in bundles.items():
+            currency = bundle['Currencies'].get(row['alpha_3'])
+            if currency is not None:
+                names[locale] = currency[1]
+        names.setdefault('en', row['name'])
+        currencies[row['alpha_3']] = dict(
+            (locale, name) for (locale, name) in names.items()
+            if locale == 'en' or name != names['en'])
+    return currencies
+
+def read_data():
+    bundles = dict((locale, read_bundle(locale)) for locale in ['en'] + LOCALES)
+    return {
+        'locales': ['en'] + LOCALES,
+        'countries': read_countries(bundles),
+        'languages': read_languages(bundles),
+        'scripts': read_scripts(bundles),
+        'currencies': read_currencies(bundles)
+    }
+
+def write_data(display_names, out_path):
+    print('writing %s/display_names.json' % out_path)
+    with open('%s/display_names.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(display_names, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
@@ -0,0 +1,40 @@
+import csv
+import json
+import sys
+
+def read_countries():
+    with open('../iso-3166/all.csv', encoding='utf-8', newline='') as csv_file:
+        return set(row['alpha_3'] for row in csv.DictReader(csv_file))
+
+def read_data():
+    countries = read_countries()
+    groups = {}
+    with open('groups.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            groups[row['code']] = {
+                'code': row['code'],
+                'name': row['name'],
+                'established': row['established'],
+                'members': []
+            }
+    with open('memberships.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            if row['alpha_3'] not in countries:
+                raise ValueError('unknown country: %s' % row['alpha_3'])
+            groups[row['group']]['members'].append({
+                'country_code': row['alpha_3'],
+                'joined': row['joined'],
+                'left': row['left'] or None,
+                'comment': row['comment'] or None
+            })
+    return groups
+
+def write_data(groups, out_path):
+    print('writing %s/groups.json' % out_path)
+    with open('%s/groups.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(groups, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
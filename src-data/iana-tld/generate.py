@@ -0,0 +1,91 @@
+import csv
+import json
+import re
+import sys
+
+IDN_COUNTRY = re.compile(r'^// (xn--[a-z0-9-]+) .*: ([A-Z]{2})\s*$')
+
+def to_ascii(label):
+    if label.isascii():
+        return label
+    return 'xn--' + label.encode('punycode').decode('ascii')
+
+def read_countries():
+    with open('../iso-3166/all.csv', encoding='utf-8', newline='') as csv_file:
+        return set(row['alpha_2'] for row in csv.DictReader(csv_file))
+
+def read_exceptions():
+    exceptions = {}
+    with open('exceptions.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            exceptions[row['tld']] = (row['kind'], row['alpha_2'] or None)
+    return exceptions
+
+def read_suffix_list():
+    tlds = []
+    idn_countries = {}
+    with open('public_suffix_list.dat', encoding='utf-8') as dat_file:
+        for line in dat_file:
+            line = line.strip()
+            if line == '// ===END ICANN DOMAINS===':
+                break
+            match = IDN_COUNTRY.match(line)
+            if match is not None:
+                idn_countries[match.group(1)] = match.group(2)
+            elif line != '' and not line.startswith('//'):
+                tld = line.split('.')[-1]
+                if tld not in tlds:
+                    tlds.append(tld)
+    return (tlds, idn_countries)
+
+def read_suffix_rules():
+    rules = set()
+    with open('public_suffix_list.dat', encoding='utf-8') as dat_file:
+        for line in dat_file:
+            line = line.strip()
+            if line == '// ===END ICANN DOMAINS===':
+                break
+            elif line != '' and not line.startswith('//'):
+                rules.add(line)
+                prefix = '!' if line.startswith('!') else ''
+                labels = line[len(prefix):].split('.')
+                rules.add(prefix + '.'.join(to_ascii(label) for label in labels))
+    return sorted(rules)
+
+def read_data():
+    countries = read_countries()
+    exceptions = read_exceptions()
+    (tlds, idn_countries) = read_suffix_list()
+    tld_data = {}
+    for tld in tlds:
+        ascii_tld = to_ascii(tld)
+        if tld in exceptions:
+            (kind, country) = exceptions[tld]
+        elif ascii_tld in idn_countries:
+            (kind, country) = ('country_code', idn_countries[ascii_tld])
+        elif len(tld) == 2 and tld.isascii():
+            (kind, country) = ('country_code', tld.upper())
+        else:
+            (kind, country) = ('generic', None)
+        if country is not None and country not in countries:
+            raise ValueError('unknown country %s for .%s' % (country, tld))
+        tld_data[tld] = {
+            'tld': tld,
+            'ascii_tld': ascii_tld,
+            'kind': kind,
+            'country_code': country
+        }
+    return tld_data
+
+def write_data(tlds, rules, out_path):
+    print('writing %s/tlds.json' % out_path)
+    with open('%s/tlds.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(tlds, ensure_ascii=False, separators=(',', ':')), file=text_file)
+    print('writing %s/public_suffixes.json' % out_path)
+    with open('%s/public_suffixes.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(rules, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), read_suffix_rules(), sys.argv[1])
@@ -0,0 +1,106 @@
+import csv
+import json
+import re
+import sys
+
+COORDINATES = re.compile(r'^([+-])(\d{2})(\d{2})(\d{2})?([+-])(\d{3})(\d{2})(\d{2})?$')
+
+def parse_angle(sign, degrees, minutes, seconds):
+    value = int(degrees) + int(minutes) / 60 + (0 if seconds is None else int(seconds)) / 3600
+    return round(-value if sign == '-' else value, 4)
+
+def parse_coordinates(text):
+    match = COORDINATES.match(text)
+    if match is None:
+        raise ValueError('bad coordinates: %s' % text)
+    return {
+        'latitude': parse_angle(*match.group(1, 2, 3, 4)),
+        'longitude': parse_angle(*match.group(5, 6, 7, 8))
+    }
+
+def read_tab(file_name):
+    rows = []
+    with open(file_name, encoding='utf-8') as tab_file:
+        for line in tab_file:
+            if line.startswith('#') or line.strip() == '':
+                continue
+            fields = line.rstrip('\n').split('\t')
+            rows.append({
+                'country_codes': fields[0].split(','),
+                'coordinates': parse_coordinates(fields[1]),
+                'id': fields[2],
+                'comment': fields[3] if len(fields) > 3 else None
+            })
+    return rows
+
+def read_tzdata():
+    zones = []
+    links = {}
+    with open('tzdata.zi', encoding='utf-8') as zi_file:
+        for line in zi_file:
+            fields = line.split()
+            if len(fields) > 1 and fields[0] == 'Z':
+                zones.append(fields[1])
+            elif len(fields) > 2 and fields[0] == 'L':
+                links[fields[2]] = fields[1]
+    for alias in links:
+        while links[alias] in links:
+            links[alias] = links[links[alias]]
+    return (zones, links)
+
+def read_windows(zones, links):
+    windows = []
+    with open('windows-zones.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            zone_ids = row['iana'].split()
+            for zone_id in zone_ids:
+                if zone_id not in zones and zone_id not in links:
+                    raise ValueError('unknown zone: %s' % zone_id)
+            windows.append({
+                'windows_id': row['windows'],
+                'territory': row['territory'],
+                'zone_ids': zone_ids
+            })
+    return windows
+
+def read_data():
+    (zones, links) = read_tzdata()
+    zone1970 = dict((row['id'], row) for row in read_tab('zone1970.tab'))
+
+    timezones = {}
+    for row in read_tab('zone.tab'):
+        shared = zone1970.get(row['id'])
+        timezones[row['id']] = {
+            'id': row['id'],
+            'country_code': row['country_codes'][0],
+            'country_codes': row['country_codes'] if shared is None else shared['country_codes'],
+            'coordinates': row['coordinates'],
+            'comment': row['comment'],
+            'alias_of': links.get(row['id'])
+        }
+    for zone in zones:
+        if zone not in timezones:
+            timezones[zone] = {
+                'id': zone,
+                'country_code': None,
+                'country_codes': [],
+                'coordinates': None,
+                'comment': None,
+                'alias_of': None
+            }
+    return (timezones, links, read_windows(zones, links))
+
+def write_json(value, file_name, out_path):
+    print('writing %s/%s' % (out_path, file_name))
+    with open('%s/%s' % (out_path, file_name), 'w', encoding='utf-8') as text_file:
+        print(json.dumps(value, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+def write_data(timezones, links, windows, out_path):
+    write_json(timezones, 'timezones.json', out_path)
+    write_json(links, 'timezone_links.json', out_path)
+    write_json(windows, 'windows_zones.json', out_path)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(*read_data(), sys.argv[1])
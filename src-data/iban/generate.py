@@ -0,0 +1,77 @@
+import csv
+import json
+import re
+import sys
+
+CHARACTER_CLASSES = {
+    'n': '[0-9]',
+    'a': '[A-Z]',
+    'c': '[0-9A-Za-z]'
+}
+
+def read_countries():
+    with open('../iso-3166/all.csv', encoding='utf-8', newline='') as csv_file:
+        return set(row['alpha_2'] for row in csv.DictReader(csv_file))
+
+def read_user_assigned():
+    with open('../iso-3166/reserved.csv', encoding='utf-8', newline='') as csv_file:
+        return set(row['code'] for row in csv.DictReader(csv_file)
+                   if row['status'] == 'user_assigned' and len(row['code']) == 2)
+
+def to_pattern(code, bban_format):
+    pattern = ''
+    for (length, fixed, kind) in re.findall(r'([0-9]+)(!?)([nac])', bban_format):
+        if fixed == '!':
+            pattern += '%s{%s}' % (CHARACTER_CLASSES[kind], length)
+        else:
+            pattern += '%s{1,%s}' % (CHARACTER_CLASSES[kind], length)
+    if re.fullmatch(r'([0-9]+!?[nac])+', bban_format) is None:
+        raise ValueError('bad BBAN format for %s: %s' % (code, bban_format))
+    return pattern
+
+def check_digits_valid(iban):
+    rearranged = iban[4:] + iban[:4]
+    return int(''.join(str(int(c, 36)) for c in rearranged)) % 97 == 1
+
+def read_data():
+    countries = read_countries()
+    user_assigned = read_user_assigned()
+    formats = {}
+    with open('iban-registry.tsv', encoding='utf-8', newline='') as tsv_file:
+        for row in csv.DictReader(tsv_file, delimiter='\t', quoting=csv.QUOTE_NONE):
+            code = row['alpha_2']
+            territories = row['territories'].split(';') if row['territories'] else []
+            if code not in countries and code not in user_assigned:
+                raise ValueError('unknown country: %s' % code)
+            for country in territories:
+                if country not in countries:
+                    raise ValueError('unknown country: %s' % country)
+            if re.fullmatch(r'[A-Z]{3}', row['currency']) is None:
+                raise ValueError('bad currency for %s: %s' % (code, row['currency']))
+            pattern = to_pattern(code, row['bban_format'])
+            example = row['example']
+            if (len(example) != int(row['length'])
+                    or not example.startswith(code)
+                    or re.fullmatch(pattern, example[4:]) is None
+                    or not check_digits_valid(example)):
+                raise ValueError('bad example for %s: %s' % (code, example))
+            formats[code] = {
+                'country_code': code,
+                'length': int(row['length']),
+                'bban_format': row['bban_format'],
+                'bban_pattern': pattern,
+                'example': example,
+                'currency_code': row['currency'],
+                'territories': territories
+            }
+    return formats
+
+def write_data(formats, out_path):
+    print('writing %s/iban_formats.json' % out_path)
+    with open('%s/iban_formats.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(formats, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
@@ -0,0 +1,49 @@
+import gettext
+import json
+import sys
+
+LANGUAGES = ['de', 'es', 'fr', 'it', 'ja', 'nl', 'ru', 'zh_CN']
+
+def read_translations():
+    translations = {}
+    for language in LANGUAGES:
+        with open('%s/iso_3166-2.mo' % language, 'rb') as mo_file:
+            translations[language] = gettext.GNUTranslations(mo_file)
+    return translations
+
+def read_data():
+    translations = read_translations()
+
+    with open('iso_3166-2.json', encoding='utf-8') as json_file:
+        iso_data = json.load(json_file)['3166-2']
+
+    subdivisions = {}
+    for row in iso_data:
+        country = row['code'].split('-')[0]
+        parent = row.get('parent')
+        if parent is not None and not parent.startswith('%s-' % country):
+            parent = '%s-%s' % (country, parent)
+        localized = {}
+        for language, translation in translations.items():
+            name = translation.gettext(row['name'])
+            if name != row['name']:
+                localized[language] = name
+        subdivisions[row['code']] = {
+            'code': row['code'],
+            'country_code': country,
+            'name': row['name'],
+            'category': row['type'],
+            'parent_code': parent,
+            'localized_names': localized
+        }
+    return subdivisions
+
+def write_data(subdivisions, out_path):
+    print('writing %s/subdivisions.json' % out_path)
+    with open('%s/subdivisions.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(subdivisions, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
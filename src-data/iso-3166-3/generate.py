@@ -0,0 +1,41 @@
+import csv
+import json
+import sys
+
+def read_successors():
+    successors = {}
+    with open('successors.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            successors[row['alpha_4']] = row['successors'].split()
+    return successors
+
+def read_data():
+    successors = read_successors()
+
+    with open('iso_3166-3.json', encoding='utf-8') as json_file:
+        iso_data = json.load(json_file)['3166-3']
+
+    countries = {}
+    for row in iso_data:
+        numeric = row.get('numeric')
+        countries[row['alpha_4']] = {
+            'code': row['alpha_4'],
+            'short_code': row['alpha_2'],
+            'long_code': row['alpha_3'],
+            'numeric_code': None if numeric is None else int(numeric),
+            'name': row['name'],
+            'withdrawal_date': row['withdrawal_date'],
+            'successor_codes': successors.get(row['alpha_4'], []),
+            'comment': row.get('comment')
+        }
+    return countries
+
+def write_data(countries, out_path):
+    print('writing %s/former_countries.json' % out_path)
+    with open('%s/former_countries.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(countries, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
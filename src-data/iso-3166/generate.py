@@ -1,41 +1,139 @@
+import gettext
+import json
 import math
 import pandas as pd
 import sys
 
+def optional_int(value):
+    return None if math.isnan(value) else int(value)
+
+WORLD_CODE = 1
+
+def make_region(code, name, kind, parent_code, country_code=None, groupings=[]):
+    return {
+        'code': code,
+        'name': name,
+        'kind': kind,
+        'parent_code': parent_code,
+        'country_code': country_code,
+        'groupings': groupings
+    }
+
+def read_groupings():
+    data_frame = pd.read_csv('m49-groupings.csv', header=0, keep_default_na=False)
+    groupings = {}
+    for row in data_frame.itertuples():
+        groupings.setdefault(row.alpha_3, []).append(row.grouping)
+    return groupings
+
+def read_iso_names():
+    with open('iso_3166-1.json', encoding='utf-8') as json_file:
+        iso_data = json.load(json_file)['3166-1']
+    with open('fr/iso_3166-1.mo', 'rb') as mo_file:
+        french = gettext.GNUTranslations(mo_file)
+    names = {}
+    for country in iso_data:
+        names[country['alpha_3']] = {
+            'iso': country['name'],
+            'common': country.get('common_name'),
+            'official': country.get('official_name'),
+            'french': french.gettext(country['name'])
+        }
+    return names
+
+def read_aliases():
+    data_frame = pd.read_csv('aliases.csv', header=0, keep_default_na=False)
+    return [(row.name, row.alpha_3, row.kind) for row in data_frame.itertuples()]
+
+def read_region_aliases():
+    data_frame = pd.read_csv('region-aliases.csv', header=0, keep_default_na=False)
+    return [(row.name, int(row.code)) for row in data_frame.itertuples()]
+
+def read_reserved():
+    data_frame = pd.read_csv('reserved.csv', header=0, keep_default_na=False)
+    return [(row.code, row.status, row.name) for row in data_frame.itertuples()]
+
+def make_names(countries, iso_names):
+    names = []
+    for country in countries:
+        names.append((country['name'], country['code'], 'exact'))
+        if country['official_name'] is not None:
+            names.append((country['official_name'], country['code'], 'exact'))
+    for country in countries:
+        names.append((country['m49_name'], country['code'], 'exact'))
+    for country in countries:
+        common = iso_names[country['code']]['common']
+        if common is not None:
+            names.append((common, country['code'], 'alias'))
+    return names + read_aliases()
+
 def read_data():
-    data_frame = pd.read_csv('all.csv', header=0)
+    iso_names = read_iso_names()
+    groupings = read_groupings()
 
-    regions = {}
+    regions = {WORLD_CODE: make_region(WORLD_CODE, 'World', 'world', None)}
+
+    # Namibia's code is NA, which pandas would otherwise read as missing
+    data_frame = pd.read_csv('all.csv', header=0, keep_default_na=False, na_values=[''])
 
     countries = []
 
     for row in data_frame.itertuples():
-        regions[int(row.country_code)] = row.name
-        if not math.isnan(row.region_code):
-            regions[int(row.region_code)] = row.region
-        if not math.isnan(row.sub_region_code):
-            regions[int(row.sub_region_code)] = row.sub_region
-        if not math.isnan(row.intermediate_region_code):
-            regions[int(row.intermediate_region_code)] = row.intermediate_region
+        country_code = int(row.country_code)
+        region_code = optional_int(row.region_code)
+        sub_region_code = optional_int(row.sub_region_code)
+        intermediate_region_code = optional_int(row.intermediate_region_code)
+
+        chain = [(country_code, row.name, 'country_or_area'),
+                 (intermediate_region_code, row.intermediate_region, 'intermediate_region'),
+                 (sub_region_code, row.sub_region, 'sub_region'),
+                 (region_code, row.region, 'continent'),
+                 (WORLD_CODE, 'World', 'world')]
+        chain = [link for link in chain if link[0] is not None]
+        country_groupings = groupings.get(row.alpha_3, [])
+        if region_code is not None and 'developed' not in country_groupings:
+            country_groupings = country_groupings + ['developing']
+        for ((code, name, kind), (parent_code, _, _)) in zip(chain, chain[1:]):
+            if code == country_code:
+                regions[code] = make_region(
+                    code, name, kind, parent_code, row.alpha_3, country_groupings)
+            else:
+                regions[code] = make_region(code, name, kind, parent_code)
 
+        names = iso_names[row.alpha_3]
         countries.append({
             'code': row.alpha_3,
             'short': row.alpha_2,
-            'country': int(row.country_code),
-            'region': None if math.isnan(row.region_code) else int(row.region_code),
-            'sub_region': None if math.isnan(row.sub_region_code) else int(row.sub_region_code),
-            'intermediate': None if math.isnan(row.intermediate_region_code) else int(row.intermediate_region_code)
+            'country': country_code,
+            'region': region_code,
+            'sub_region': sub_region_code,
+            'intermediate': intermediate_region_code,
+            'm49_name': row.name,
+            'name': names['iso'],
+            'official_name': None if names['official'] == names['iso'] else names['official'],
+            'french_name': names['french']
         })
-    return (regions, countries)
+    for (_, code) in read_region_aliases():
+        assert code in regions, code
+    return (regions, read_region_aliases(), countries, make_names(countries, iso_names), read_reserved())
 
-def write_data(regions, countries, out_path):
+def write_data(regions, region_names, countries, names, reserved, out_path):
     r_rows = map(
-        lambda rinfo: '"%s":"%s"' % (rinfo[0], rinfo[1]),
+        lambda rinfo: '"%s":%s' % (
+            rinfo[0], json.dumps(rinfo[1], ensure_ascii=False, separators=(',', ':'))),
         regions.items())
     print('writing %s/regions.json' % out_path)
-    with open('%s/regions.json' % out_path, 'w') as text_file:
+    with open('%s/regions.json' % out_path, 'w', encoding='utf-8') as text_file:
         print('{%s}' % ','.join(r_rows), file=text_file)
 
+    rn_rows = map(
+        lambda rninfo: '{"name":%s,"code":%s}' % (
+            json.dumps(rninfo[0], ensure_ascii=False), rninfo[1]),
+        region_names)
+    print('writing %s/region_names.json' % out_path)
+    with open('%s/region_names.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print('[%s]' % ','.join(rn_rows), file=text_file)
+
     c_rows = map(
         lambda cinfo:
            '"%s":{%s}' % (
@@ -46,14 +144,33 @@ def write_data(regions, countries, out_path):
                     '"country_code":%s' % cinfo['country'],
                     '"region_code":%s' % ('null' if cinfo['region'] is None else '%s' % cinfo['region']),
                     '"sub_region_code":%s' % ('null' if cinfo['sub_region'] is None else '%s' % cinfo['sub_region']),
-                    '"intermediate_region_code":%s' % ('null' if cinfo['intermediate'] is None else '%s' % cinfo['intermediate'])
+                    '"intermediate_region_code":%s' % ('null' if cinfo['intermediate'] is None else '%s' % cinfo['intermediate']),
+                    '"name":%s' % json.dumps(cinfo['name'], ensure_ascii=False),
+                    '"official_name":%s' % json.dumps(cinfo['official_name'], ensure_ascii=False),
+                    '"french_name":%s' % json.dumps(cinfo['french_name'], ensure_ascii=False)
                 ])),
         countries)
     print('writing %s/countries.json' % out_path)
-    with open('%s/countries.json' % out_path, 'w') as text_file:
+    with open('%s/countries.json' % out_path, 'w', encoding='utf-8') as text_file:
         print('{%s}' % ','.join(c_rows), file=text_file)
 
+    n_rows = map(
+        lambda ninfo: '{"name":%s,"code":"%s","kind":"%s"}' % (
+            json.dumps(ninfo[0], ensure_ascii=False), ninfo[1], ninfo[2]),
+        names)
+    print('writing %s/country_names.json' % out_path)
+    with open('%s/country_names.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print('[%s]' % ','.join(n_rows), file=text_file)
+
+    x_rows = map(
+        lambda xinfo: '"%s":{"code":"%s","status":"%s","name":%s}' % (
+            xinfo[0], xinfo[0], xinfo[1], json.dumps(xinfo[2], ensure_ascii=False)),
+        reserved)
+    print('writing %s/reserved_countries.json' % out_path)
+    with open('%s/reserved_countries.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print('{%s}' % ','.join(x_rows), file=text_file)
+
 if len(sys.argv) < 2:
     print('Error: need a path argument')
 else:
-    write_data(*read_data(), sys.argv[1])
\ No newline at end of file
+    write_data(*read_data(), sys.argv[1])
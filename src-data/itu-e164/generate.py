@@ -0,0 +1,26 @@
+import csv
+import json
+import sys
+
+def read_data():
+    calling_codes = []
+    with open('calling-codes.csv', encoding='utf-8', newline='') as csv_file:
+        for row in csv.DictReader(csv_file):
+            calling_codes.append({
+                'country_code': row['alpha_2'],
+                'calling_code': row['calling_code'],
+                'area_codes': row['area_codes'].split(),
+                'international_prefix': row['international_prefix'],
+                'trunk_prefix': None if row['trunk_prefix'] == '' else row['trunk_prefix']
+            })
+    return calling_codes
+
+def write_data(calling_codes, out_path):
+    print('writing %s/calling_codes.json' % out_path)
+    with open('%s/calling_codes.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(calling_codes, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
@@ -0,0 +1,64 @@
+import csv
+import json
+import re
+import sys
+
+def read_countries():
+    with open('../iso-3166/all.csv', encoding='utf-8', newline='') as csv_file:
+        return set(row['alpha_2'] for row in csv.DictReader(csv_file))
+
+def ascii_digits(pattern):
+    # `\d` matches any Unicode decimal digit in the Rust regex crate
+    if not pattern:
+        return pattern
+    result = ''
+    in_class = False
+    index = 0
+    while index < len(pattern):
+        c = pattern[index]
+        if c == '\\' and pattern[index + 1] == 'd':
+            result += '0-9' if in_class else '[0-9]'
+            index += 2
+            continue
+        if c == '\\':
+            result += pattern[index:index + 2]
+            index += 2
+            continue
+        if c == '[':
+            in_class = True
+        elif c == ']':
+            in_class = False
+        result += c
+        index += 1
+    return result
+
+def read_data():
+    countries = read_countries()
+    postal_codes = {}
+    with open('postal-codes.tsv', encoding='utf-8', newline='') as tsv_file:
+        for row in csv.DictReader(tsv_file, delimiter='\t', quoting=csv.QUOTE_NONE):
+            if row['alpha_2'] not in countries:
+                raise ValueError('unknown country: %s' % row['alpha_2'])
+            examples = row['examples'].split(';')
+            for example in examples:
+                if re.fullmatch(row['pattern'], example) is None:
+                    raise ValueError('bad example for %s: %s' % (row['alpha_2'], example))
+            postal_codes[row['alpha_2']] = {
+                'country_code': row['alpha_2'],
+                'pattern': ascii_digits(row['pattern']),
+                'examples': examples,
+                'required': row['required'] == 'Y',
+                'compact_pattern': ascii_digits(row['compact']) or None,
+                'compact_template': row['template'] or None
+            }
+    return postal_codes
+
+def write_data(postal_codes, out_path):
+    print('writing %s/postal_codes.json' % out_path)
+    with open('%s/postal_codes.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(postal_codes, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
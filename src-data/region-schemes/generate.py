@@ -0,0 +1,65 @@
+import csv
+import json
+import sys
+
+M49_CONTINENTS = {
+    '002': 'Africa',
+    '009': 'Oceania',
+    '019': 'Americas',
+    '142': 'Asia',
+    '150': 'Europe'
+}
+
+def read_countries():
+    with open('../iso-3166/all.csv', encoding='utf-8', newline='') as csv_file:
+        return list(csv.DictReader(csv_file))
+
+def make_m49_groups(countries):
+    groups = {}
+    for country in countries:
+        region_code = country['region_code']
+        if region_code != '':
+            code = str(int(region_code))
+            group = groups.setdefault(code, {
+                'scheme': 'm49',
+                'code': code,
+                'name': M49_CONTINENTS[region_code],
+                'members': []
+            })
+            group['members'].append(country['alpha_3'])
+    for group in groups.values():
+        group['members'].sort()
+    return sorted(groups.values(), key=lambda group: int(group['code']))
+
+def read_data():
+    countries = read_countries()
+    alpha_3 = dict((country['alpha_2'], country['alpha_3']) for country in countries)
+    groups = make_m49_groups(countries)
+    with open('groups.tsv', encoding='utf-8', newline='') as tsv_file:
+        for row in csv.DictReader(tsv_file, delimiter='\t'):
+            members = []
+            for member in row['members'].split():
+                if member not in alpha_3:
+                    raise ValueError('unknown country: %s' % member)
+                members.append(alpha_3[member])
+            groups.append({
+                'scheme': row['scheme'],
+                'code': row['code'],
+                'name': row['name'],
+                'members': sorted(members)
+            })
+    for scheme in set(group['scheme'] for group in groups):
+        members = [m for group in groups if group['scheme'] == scheme for m in group['members']]
+        if len(members) != len(set(members)):
+            raise ValueError('country in more than one group of scheme: %s' % scheme)
+    return groups
+
+def write_data(groups, out_path):
+    print('writing %s/region_schemes.json' % out_path)
+    with open('%s/region_schemes.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(groups, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
@@ -0,0 +1,49 @@
+import csv
+import json
+import re
+import sys
+
+def read_countries():
+    with open('../iso-3166/all.csv', encoding='utf-8', newline='') as csv_file:
+        return set(row['alpha_2'] for row in csv.DictReader(csv_file))
+
+def read_subdivisions():
+    with open('../iso-3166-2/iso_3166-2.json', encoding='utf-8') as json_file:
+        return set(subdivision['code'] for subdivision in json.load(json_file)['3166-2'])
+
+def read_data():
+    countries = read_countries()
+    subdivisions = read_subdivisions()
+    schemes = {}
+    with open('tax-ids.tsv', encoding='utf-8', newline='') as tsv_file:
+        for row in csv.DictReader(tsv_file, delimiter='\t', quoting=csv.QUOTE_NONE):
+            code = row['code']
+            if row['alpha_2'] not in countries:
+                raise ValueError('unknown country for %s: %s' % (code, row['alpha_2']))
+            if row['subdivision'] and row['subdivision'] not in subdivisions:
+                raise ValueError('unknown subdivision for %s: %s' % (code, row['subdivision']))
+            examples = row['examples'].split(';')
+            for example in examples:
+                if re.fullmatch(row['pattern'], example) is None:
+                    raise ValueError('bad example for %s: %s' % (code, example))
+            schemes[code] = {
+                'code': code,
+                'country_code': row['alpha_2'],
+                'subdivision_code': row['subdivision'] or None,
+                'name': row['name'],
+                'prefix': row['prefix'] or None,
+                'pattern': row['pattern'],
+                'checksum': row['checksum'],
+                'examples': examples
+            }
+    return schemes
+
+def write_data(schemes, out_path):
+    print('writing %s/tax_ids.json' % out_path)
+    with open('%s/tax_ids.json' % out_path, 'w', encoding='utf-8') as text_file:
+        print(json.dumps(schemes, ensure_ascii=False, separators=(',', ':')), file=text_file)
+
+if len(sys.argv) < 2:
+    print('Error: need a path argument')
+else:
+    write_data(read_data(), sys.argv[1])
@@ -0,0 +1,810 @@
+/*!
+Postal address formats, and the formatting of mailing labels, for each country.
+
+The layout of a postal address differs by country; the order of the fields,
+which of them are required, which are written in upper case, and what the
+fields are called locally. For example an address in the United States ends
+with the city, state, and ZIP code on a single line (`MOUNTAIN VIEW, CA
+94043`), whereas in Japan the address starts with the postal code and
+prefecture. Countries without a specific format use a default one. Some
+countries, such as Japan and China, also have a separate format for
+addresses written in the Latin script, which is used for the `Latin` and
+`International` styles.
+
+The administrative area of an address (the state, province, prefecture, ...)
+may be provided as an ISO 3166-2 subdivision code, with or without the
+country prefix, or as a name; it is written as the subdivision registry's
+code or name, depending on the country's conventions. A subdivision code
+written as a name uses the registry's (English) name; a name is written as
+provided.
+
+Field labels, such as `Prefecture` or `ZIP code`, are in English only.
+
+```rust
+use locale_codes::address::{self, Address, AddressStyle};
+use locale_codes::country;
+
+let address = Address {
+    recipient: "Jane Doe".to_string(),
+    street_address: vec!["1600 Amphitheatre Parkway".to_string()],
+    locality: "Mountain View".to_string(),
+    administrative_area: "California".to_string(),
+    postal_code: "94043".to_string(),
+    ..Default::default()
+};
+let us = country::lookup("US").unwrap();
+assert_eq!(
+    address::format_address(&address, us, AddressStyle::Domestic),
+    "Jane Doe\n1600 Amphitheatre Parkway\nMOUNTAIN VIEW, CA 94043"
+);
+```
+
+## Source
+
+The data used here was compiled from the address metadata published by
+Google's [libaddressinput](https://github.com/google/libaddressinput) project.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::{self, CountryInfo};
+use crate::error::{check_alphabetic, LookupError};
+use crate::subdivision::{self, SubdivisionInfo};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A field within a postal address.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressField {
+    /// The name of the person the item is addressed to.
+    Recipient,
+    /// The name of the organization the item is addressed to.
+    Organization,
+    /// One or more lines of street address, building, or P.O. box.
+    StreetAddress,
+    /// A part of the locality, such as a neighborhood or district.
+    DependentLocality,
+    /// The city, town, or post town.
+    Locality,
+    /// The top-level administrative area, such as a state or province.
+    AdministrativeArea,
+    /// The postal code.
+    PostalCode,
+    /// The sorting code, used in a few countries such as France.
+    SortingCode,
+}
+
+/// The local name used for the administrative area of an address.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AdministrativeAreaType {
+    /// Area, as used in Hong Kong.
+    Area,
+    /// Canton, as used in Switzerland.
+    Canton,
+    /// County.
+    County,
+    /// Department.
+    Department,
+    /// Do/Si, as used in South Korea.
+    DoSi,
+    /// Emirate.
+    Emirate,
+    /// Governorate.
+    Governorate,
+    /// Oblast.
+    Oblast,
+    /// Parish.
+    Parish,
+    /// Prefecture.
+    Prefecture,
+    /// Province.
+    Province,
+    /// Region.
+    Region,
+    /// State.
+    State,
+}
+
+/// The local name used for the locality of an address.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalityType {
+    /// City.
+    City,
+    /// District.
+    District,
+    /// Post town, as used in the United Kingdom.
+    PostTown,
+    /// Suburb.
+    Suburb,
+}
+
+/// The local name used for the dependent locality of an address.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DependentLocalityType {
+    /// District.
+    District,
+    /// Neighborhood.
+    Neighborhood,
+    /// Suburb.
+    Suburb,
+    /// Townland, as used in Ireland.
+    Townland,
+    /// Village or township, as used in Malaysia.
+    VillageTownship,
+}
+
+/// The local name used for the postal code of an address.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PostalCodeType {
+    /// Eircode, as used in Ireland.
+    Eircode,
+    /// PIN code, as used in India.
+    Pin,
+    /// Postal code.
+    Postal,
+    /// ZIP code, as used in the United States.
+    Zip,
+}
+
+/// How the administrative area is written in an address.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubdivisionStyle {
+    /// The ISO 3166-2 subdivision code, without the country prefix; for
+    /// example `CA` for California.
+    Code,
+    /// The subdivision name, for example `Bavaria`.
+    Name,
+}
+
+/// The style used when formatting an address.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressStyle {
+    /// An address for mail sent within the country.
+    Domestic,
+    /// An address for mail sent within the country, written in the Latin
+    /// script; for example a romanized Japanese address. The country's
+    /// Latin format is used, if it has one.
+    Latin,
+    /// An address for mail sent from another country, written in the Latin
+    /// script as for `Latin`; the country's standard English display name,
+    /// such as `SOUTH KOREA` rather than the ISO name `KOREA, REPUBLIC OF`,
+    /// is added in upper case as the last line.
+    International,
+}
+
+/// A postal address; empty fields are omitted when the address is formatted.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Address {
+    /// The name of the person the item is addressed to.
+    pub recipient: String,
+    /// The name of the organization the item is addressed to.
+    pub organization: String,
+    /// One or more lines of street address, building, or P.O. box.
+    pub street_address: Vec<String>,
+    /// A part of the locality, such as a neighborhood or district.
+    pub dependent_locality: String,
+    /// The city, town, or post town.
+    pub locality: String,
+    /// The top-level administrative area; either an ISO 3166-2 subdivision
+    /// code, with or without the country prefix, or a name.
+    pub administrative_area: String,
+    /// The postal code.
+    pub postal_code: String,
+    /// The sorting code.
+    pub sorting_code: String,
+}
+
+/// A representation of the address format used within a country.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddressFormatInfo {
+    /// The ISO-3166, part 1, 2-character identifier of the country, or `ZZ`
+    /// for the default format.
+    pub country_code: String,
+    /// The libaddressinput format string; for example `%N%n%O%n%A%n%C, %S %Z`
+    /// where each `%` and letter is a field and `%n` is a new line.
+    pub format: String,
+    /// The libaddressinput format string for addresses written in the Latin
+    /// script, if it differs from `format`.
+    pub latin_format: Option<String>,
+    /// The fields required in an address.
+    pub required: Vec<AddressField>,
+    /// The fields written in upper case.
+    pub upper: Vec<AddressField>,
+    /// The local name for the administrative area.
+    pub administrative_area_type: AdministrativeAreaType,
+    /// The local name for the locality.
+    pub locality_type: LocalityType,
+    /// The local name for the dependent locality.
+    pub dependent_locality_type: DependentLocalityType,
+    /// The local name for the postal code.
+    pub postal_code_type: PostalCodeType,
+    /// How the administrative area is written.
+    pub subdivision_style: SubdivisionStyle,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref ADDRESS_FORMATS: HashMap<String, AddressFormatInfo> =
+        load_address_formats_from_json();
+}
+
+/// Lookup an `AddressFormatInfo` based on the country's ISO-3166
+/// 2-character identifier, returning `None` if the country does not have a
+/// specific format.
+pub fn lookup(code: &str) -> Option<&'static AddressFormatInfo> {
+    debug!("address::lookup: {}", code);
+    if code == DEFAULT_CODE {
+        None
+    } else {
+        ADDRESS_FORMATS.get(code)
+    }
+}
+
+/// Lookup an `AddressFormatInfo` based on the country's ISO-3166 2-character
+/// identifier, returning a `LookupError` rather than `None` if the code is
+/// malformed or unknown.
+pub fn try_lookup(code: &str) -> Result<&'static AddressFormatInfo, LookupError> {
+    debug!("address::try_lookup: {}", code);
+    check_alphabetic(
+        code,
+        &[2],
+        |code| code.to_ascii_uppercase(),
+        |code| lookup(code).is_some(),
+    )?;
+    match lookup(code) {
+        Some(info) => Ok(info),
+        None => Err(LookupError::unknown(
+            code,
+            ADDRESS_FORMATS
+                .keys()
+                .map(|k| k.as_str())
+                .filter(|k| *k != DEFAULT_CODE),
+        )),
+    }
+}
+
+/// Return the default address format, used for countries without a
+/// specific format.
+pub fn default_format() -> &'static AddressFormatInfo {
+    &ADDRESS_FORMATS[DEFAULT_CODE]
+}
+
+/// Return the address format used within the provided country, or the
+/// default format if the country does not have a specific one.
+pub fn for_country(country: &CountryInfo) -> &'static AddressFormatInfo {
+    lookup(&country.short_code).unwrap_or_else(default_format)
+}
+
+/// Format the address as a mailing label for the provided country, with
+/// each line separated by `\n`.
+pub fn format_address(address: &Address, country: &CountryInfo, style: AddressStyle) -> String {
+    let format = for_country(country);
+    let mut lines: Vec<String> = Vec::new();
+    for line in format.format_for(style).split("%n") {
+        let tokens = tokenize(line);
+        let values: Vec<Option<String>> = tokens
+            .iter()
+            .map(|token| match token {
+                Token::Field(field) => Some(format.field_value(address, *field, country)),
+                Token::Literal(_) => None,
+            })
+            .collect();
+        let mut rendered = String::new();
+        for (index, token) in tokens.iter().enumerate() {
+            match token {
+                Token::Field(_) => rendered.push_str(values[index].as_ref().unwrap()),
+                Token::Literal(text) => {
+                    let emitted_before = values[..index]
+                        .iter()
+                        .any(|value| value.as_ref().map_or(false, |value| !value.is_empty()));
+                    let field_before = values[..index].iter().any(|value| value.is_some());
+                    let include = match values[index..].iter().find_map(|value| value.as_ref()) {
+                        Some(next) => !next.is_empty() && (emitted_before || !field_before),
+                        None => emitted_before,
+                    };
+                    if include {
+                        rendered.push_str(text);
+                    }
+                }
+            }
+        }
+        lines.extend(
+            rendered
+                .split('\n')
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string),
+        );
+    }
+    if style == AddressStyle::International {
+        let name = country::display_name(&country.code, "en").unwrap_or(&country.name);
+        lines.push(name.to_uppercase());
+    }
+    lines.join("\n")
+}
+
+/// Return all the ISO-3166 2-character country codes with a specific
+/// address format.
+pub fn all_codes() -> Vec<String> {
+    ADDRESS_FORMATS
+        .keys()
+        .filter(|code| code.as_str() != DEFAULT_CODE)
+        .cloned()
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl AddressFormatInfo {
+    /// Return the format string used for the provided style; the Latin
+    /// format, if there is one, for the `Latin` and `International` styles.
+    pub fn format_for(&self, style: AddressStyle) -> &str {
+        match (style, &self.latin_format) {
+            (AddressStyle::Domestic, _) | (_, None) => &self.format,
+            (_, Some(latin_format)) => latin_format,
+        }
+    }
+
+    /// Return the fields used in this format, in the order they are written.
+    pub fn fields(&self) -> Vec<AddressField> {
+        tokenize(&self.format.replace("%n", ""))
+            .into_iter()
+            .filter_map(|token| match token {
+                Token::Field(field) => Some(field),
+                Token::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the field is required in an address.
+    pub fn is_required(&self, field: AddressField) -> bool {
+        self.required.contains(&field)
+    }
+
+    /// Returns `true` if the field is written in upper case.
+    pub fn is_upper(&self, field: AddressField) -> bool {
+        self.upper.contains(&field)
+    }
+
+    /// Return the local label for the field; for example `Prefecture` for
+    /// the administrative area of a Japanese address. Labels are only
+    /// available in English.
+    pub fn label(&self, field: AddressField) -> &'static str {
+        match field {
+            AddressField::Recipient => "Name",
+            AddressField::Organization => "Organization",
+            AddressField::StreetAddress => "Street address",
+            AddressField::DependentLocality => self.dependent_locality_type.label(),
+            AddressField::Locality => self.locality_type.label(),
+            AddressField::AdministrativeArea => self.administrative_area_type.label(),
+            AddressField::PostalCode => self.postal_code_type.label(),
+            AddressField::SortingCode => "Sorting code",
+        }
+    }
+
+    /// Return the required fields that are empty in the provided address.
+    pub fn missing_fields(&self, address: &Address) -> Vec<AddressField> {
+        self.required
+            .iter()
+            .filter(|field| match field {
+                AddressField::StreetAddress => address
+                    .street_address
+                    .iter()
+                    .all(|line| line.trim().is_empty()),
+                _ => raw_value(address, **field).trim().is_empty(),
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn field_value(&self, address: &Address, field: AddressField, country: &CountryInfo) -> String {
+        let value = match field {
+            AddressField::StreetAddress => address.street_address.join("\n"),
+            AddressField::AdministrativeArea => {
+                let value = address.administrative_area.trim();
+                match find_subdivision(value, country) {
+                    Some(subdivision) => match self.subdivision_style {
+                        SubdivisionStyle::Code => subdivision
+                            .code
+                            .split('-')
+                            .nth(1)
+                            .unwrap_or_default()
+                            .to_string(),
+                        SubdivisionStyle::Name if is_code(value, subdivision) => {
+                            subdivision.name.to_string()
+                        }
+                        SubdivisionStyle::Name => value.to_string(),
+                    },
+                    None => value.to_string(),
+                }
+            }
+            _ => raw_value(address, field).trim().to_string(),
+        };
+        if self.is_upper(field) {
+            value.to_uppercase()
+        } else {
+            value
+        }
+    }
+}
+
+impl AdministrativeAreaType {
+    /// Return the label for this type, in English.
+    pub fn label(&self) -> &'static str {
+        match self {
+            AdministrativeAreaType::Area => "Area",
+            AdministrativeAreaType::Canton => "Canton",
+            AdministrativeAreaType::County => "County",
+            AdministrativeAreaType::Department => "Department",
+            AdministrativeAreaType::DoSi => "Do/Si",
+            AdministrativeAreaType::Emirate => "Emirate",
+            AdministrativeAreaType::Governorate => "Governorate",
+            AdministrativeAreaType::Oblast => "Oblast",
+            AdministrativeAreaType::Parish => "Parish",
+            AdministrativeAreaType::Prefecture => "Prefecture",
+            AdministrativeAreaType::Province => "Province",
+            AdministrativeAreaType::Region => "Region",
+            AdministrativeAreaType::State => "State",
+        }
+    }
+}
+
+impl LocalityType {
+    /// Return the label for this type, in English.
+    pub fn label(&self) -> &'static str {
+        match self {
+            LocalityType::City => "City",
+            LocalityType::District => "District",
+            LocalityType::PostTown => "Post town",
+            LocalityType::Suburb => "Suburb",
+        }
+    }
+}
+
+impl DependentLocalityType {
+    /// Return the label for this type, in English.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DependentLocalityType::District => "District",
+            DependentLocalityType::Neighborhood => "Neighborhood",
+            DependentLocalityType::Suburb => "Suburb",
+            DependentLocalityType::Townland => "Townland",
+            DependentLocalityType::VillageTownship => "Village/Township",
+        }
+    }
+}
+
+impl PostalCodeType {
+    /// Return the label for this type, in English.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PostalCodeType::Eircode => "Eircode",
+            PostalCodeType::Pin => "PIN code",
+            PostalCodeType::Postal => "Postal code",
+            PostalCodeType::Zip => "ZIP code",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const DEFAULT_CODE: &str = "ZZ";
+
+enum Token {
+    Field(AddressField),
+    Literal(String),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn tokenize(line: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut literal = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        let field = match c {
+            '%' => match chars.next() {
+                Some('N') => Some(AddressField::Recipient),
+                Some('O') => Some(AddressField::Organization),
+                Some('A') => Some(AddressField::StreetAddress),
+                Some('D') => Some(AddressField::DependentLocality),
+                Some('C') => Some(AddressField::Locality),
+                Some('S') => Some(AddressField::AdministrativeArea),
+                Some('Z') => Some(AddressField::PostalCode),
+                Some('X') => Some(AddressField::SortingCode),
+                _ => None,
+            },
+            _ => {
+                literal.push(c);
+                None
+            }
+        };
+        if let Some(field) = field {
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(literal));
+                literal = String::new();
+            }
+            tokens.push(Token::Field(field));
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    tokens
+}
+
+fn raw_value(address: &Address, field: AddressField) -> &str {
+    match field {
+        AddressField::Recipient => &address.recipient,
+        AddressField::Organization => &address.organization,
+        AddressField::StreetAddress => "",
+        AddressField::DependentLocality => &address.dependent_locality,
+        AddressField::Locality => &address.locality,
+        AddressField::AdministrativeArea => &address.administrative_area,
+        AddressField::PostalCode => &address.postal_code,
+        AddressField::SortingCode => &address.sorting_code,
+    }
+}
+
+fn is_code(value: &str, subdivision: &SubdivisionInfo) -> bool {
+    let code = value.to_uppercase();
+    subdivision.code == code || subdivision.code.split('-').nth(1) == Some(code.as_str())
+}
+
+fn find_subdivision(value: &str, country: &CountryInfo) -> Option<&'static SubdivisionInfo> {
+    if value.is_empty() {
+        return None;
+    }
+    let code = value.to_uppercase();
+    match subdivision::lookup(&code)
+        .or_else(|| subdivision::lookup(&format!("{}-{}", country.short_code, code)))
+    {
+        Some(subdivision) if subdivision.country_code == country.short_code => Some(subdivision),
+        _ => {
+            let name = value.to_lowercase();
+            subdivision::subdivisions_of(country)
+                .into_iter()
+                .find(|subdivision| {
+                    subdivision.name.to_lowercase() == name
+                        || subdivision
+                            .localized_names
+                            .values()
+                            .any(|localized| localized.to_lowercase() == name)
+                })
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_address_formats_from_json() -> HashMap<String, AddressFormatInfo> {
+    info!("load_address_formats_from_json - loading JSON");
+    let raw_data = include_bytes!("data/address_formats.json");
+    let format_map: HashMap<String, AddressFormatInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_address_formats_from_json - loaded {} address formats",
+        format_map.len()
+    );
+    format_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::country;
+    use crate::error::LookupErrorKind;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_address_format_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"JP".to_string()));
+        assert!(!codes.contains(&"ZZ".to_string()));
+    }
+
+    #[test]
+    fn test_good_address_format() {
+        let us = country::lookup("USA").unwrap();
+        let format = for_country(us);
+        assert_eq!(
+            format.fields(),
+            vec![
+                AddressField::Recipient,
+                AddressField::Organization,
+                AddressField::StreetAddress,
+                AddressField::Locality,
+                AddressField::AdministrativeArea,
+                AddressField::PostalCode,
+            ]
+        );
+        assert!(format.is_required(AddressField::PostalCode));
+        assert!(!format.is_required(AddressField::Recipient));
+        assert!(format.is_upper(AddressField::Locality));
+        assert_eq!(format.label(AddressField::AdministrativeArea), "State");
+        assert_eq!(format.label(AddressField::PostalCode), "ZIP code");
+
+        let japan = country::lookup("JP").unwrap();
+        assert_eq!(
+            for_country(japan).label(AddressField::AdministrativeArea),
+            "Prefecture"
+        );
+    }
+
+    #[test]
+    fn test_default_address_format() {
+        assert!(lookup("ZZ").is_none());
+        let nauru = country::lookup("NRU").unwrap();
+        assert_eq!(for_country(nauru).country_code, "ZZ");
+        assert_eq!(
+            for_country(nauru).required,
+            vec![AddressField::StreetAddress, AddressField::Locality]
+        );
+    }
+
+    #[test]
+    fn test_format_address() {
+        let address = Address {
+            recipient: "John Smith".to_string(),
+            street_address: vec!["Flat 2".to_string(), "10 Downing Street".to_string()],
+            locality: "London".to_string(),
+            postal_code: "SW1A 2AA".to_string(),
+            ..Default::default()
+        };
+        let uk = country::lookup("GB").unwrap();
+        assert_eq!(
+            format_address(&address, uk, AddressStyle::Domestic),
+            "John Smith\nFlat 2\n10 Downing Street\nLONDON\nSW1A 2AA"
+        );
+
+        let address = Address {
+            organization: "Example AG".to_string(),
+            street_address: vec!["Bahnhofstrasse 1".to_string()],
+            locality: "Zürich".to_string(),
+            postal_code: "8001".to_string(),
+            ..Default::default()
+        };
+        let switzerland = country::lookup("CH").unwrap();
+        assert_eq!(
+            format_address(&address, switzerland, AddressStyle::Latin),
+            "Example AG\nBahnhofstrasse 1\nCH-8001 Zürich"
+        );
+        assert_eq!(
+            format_address(&address, switzerland, AddressStyle::International),
+            "Example AG\nBahnhofstrasse 1\nCH-8001 Zürich\nSWITZERLAND"
+        );
+
+        let address = Address {
+            recipient: "Hong Gildong".to_string(),
+            street_address: vec!["Sejong-daero 209".to_string()],
+            locality: "Jongno-gu".to_string(),
+            administrative_area: "Seoul".to_string(),
+            postal_code: "03171".to_string(),
+            ..Default::default()
+        };
+        let korea = country::lookup("KR").unwrap();
+        assert!(
+            format_address(&address, korea, AddressStyle::International).ends_with("\nSOUTH KOREA")
+        );
+    }
+
+    #[test]
+    fn test_format_address_subdivisions() {
+        let canada = country::lookup("CA").unwrap();
+        for area in &["CA-ON", "on", "Ontario"] {
+            let address = Address {
+                street_address: vec!["111 Wellington St".to_string()],
+                locality: "Ottawa".to_string(),
+                administrative_area: area.to_string(),
+                postal_code: "K1A 0A9".to_string(),
+                ..Default::default()
+            };
+            assert_eq!(
+                format_address(&address, canada, AddressStyle::Domestic),
+                "111 WELLINGTON ST\nOTTAWA ON K1A 0A9"
+            );
+        }
+
+        let japan = country::lookup("JP").unwrap();
+        let address = Address {
+            recipient: "山田太郎".to_string(),
+            street_address: vec!["千代田区千代田1-1".to_string()],
+            administrative_area: "東京都".to_string(),
+            postal_code: "100-8111".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_address(&address, japan, AddressStyle::Domestic),
+            "〒100-8111\n東京都\n千代田区千代田1-1\n山田太郎"
+        );
+
+        let address = Address {
+            recipient: "Taro Yamada".to_string(),
+            street_address: vec!["1-1 Chiyoda, Chiyoda-ku".to_string()],
+            administrative_area: "JP-13".to_string(),
+            postal_code: "100-8111".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_address(&address, japan, AddressStyle::Latin),
+            "Taro Yamada\n1-1 Chiyoda, Chiyoda-ku, TOKYO\n100-8111"
+        );
+        assert_eq!(
+            format_address(&address, japan, AddressStyle::International),
+            "Taro Yamada\n1-1 Chiyoda, Chiyoda-ku, TOKYO\n100-8111\nJAPAN"
+        );
+
+        let address = Address {
+            street_address: vec!["1 Main Street".to_string()],
+            locality: "Springfield".to_string(),
+            administrative_area: "Nowhere".to_string(),
+            ..Default::default()
+        };
+        let us = country::lookup("US").unwrap();
+        assert_eq!(
+            format_address(&address, us, AddressStyle::Domestic),
+            "1 Main Street\nSPRINGFIELD, NOWHERE"
+        );
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let us = country::lookup("US").unwrap();
+        let address = Address {
+            street_address: vec![" ".to_string()],
+            locality: "Mountain View".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            for_country(us).missing_fields(&address),
+            vec![
+                AddressField::StreetAddress,
+                AddressField::AdministrativeArea,
+                AddressField::PostalCode,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_try_address_lookup() {
+        assert_eq!(try_lookup("JP").unwrap().country_code, "JP");
+        assert_eq!(
+            try_lookup("jp").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("J").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("QQ").unwrap_err().kind(),
+            LookupErrorKind::Unknown
+        );
+    }
+}
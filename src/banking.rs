@@ -0,0 +1,493 @@
+/*!
+Validation of International Bank Account Numbers (IBAN) and Business
+Identifier Codes (BIC).
+
+An IBAN, ISO 13616, is made up of a 2-character country code, 2 check digits,
+and a Basic Bank Account Number (BBAN) whose length and structure is specific
+to each country. An IBAN is valid if it has the correct length and structure
+for it's country, and the ISO 7064 MOD 97-10 checksum over the whole number is
+correct. IBANs are accepted in either the electronic form, with no spaces, or
+the print form, in groups of four characters.
+
+A BIC, ISO 9362, also known as a SWIFT code, identifies a bank; it is made up
+of a 4-character institution code, a 2-character country code, a
+2-character location code, and an optional 3-character branch code.
+
+In both cases the embedded country code is checked against the country
+registry, and participation in the Single Euro Payments Area (SEPA) is taken
+from the `group` registry. Kosovo, which has no ISO 3166-1 code, uses the
+user-assigned code `XK`; see
+[`country::lookup_reserved`](../country/fn.lookup_reserved.html).
+
+```rust
+use locale_codes::banking;
+
+let iban = banking::parse_iban("gb29 nwbk 6016 1331 9268 19").unwrap();
+assert_eq!(iban.country_code, "GB");
+assert_eq!(iban.to_print_format(), "GB29 NWBK 6016 1331 9268 19");
+assert!(iban.is_sepa("2024-01-01"));
+assert!(!banking::is_valid_iban("GB28NWBK60161331926819"));
+```
+
+## Source
+
+The data used here was compiled from the IBAN Registry published by SWIFT,
+the ISO 13616 registration authority, the currency of each country was
+added by hand.
+*/
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::country::{self, AssignmentStatus, CountryInfo};
+use crate::currency::{self, CurrencyInfo};
+use crate::error::{check_alphabetic, LookupError};
+use crate::group;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A representation of the IBAN format used within a country.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IbanFormatInfo {
+    /// The ISO-3166, part 1, 2-character identifier of the country, used as
+    /// the IBAN prefix; this is the user-assigned code `XK` for Kosovo.
+    pub country_code: String,
+    /// The total length of an IBAN, in electronic form.
+    pub length: usize,
+    /// The structure of the BBAN in the registry's notation; for example
+    /// `4!a6!n8!n`.
+    pub bban_format: String,
+    /// A regular expression matching the BBAN.
+    pub bban_pattern: String,
+    /// An example IBAN, in electronic form.
+    pub example: String,
+    /// The ISO-4217 alphabetic identifier of the currency of the country;
+    /// dependent territories may use another currency.
+    pub currency_code: String,
+    /// The ISO-3166, part 1, 2-character identifiers of dependent territories
+    /// that use this country's IBAN format and prefix.
+    pub territories: Vec<String>,
+}
+
+/// A validated IBAN.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Iban {
+    /// The IBAN in electronic form; upper case, with no spaces.
+    pub value: String,
+    /// The ISO-3166, part 1, 2-character identifier of the country prefix.
+    pub country_code: String,
+}
+
+/// A validated BIC.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Bic {
+    /// The BIC; upper case, with no spaces.
+    pub value: String,
+    /// The 4-character institution (bank) code.
+    pub bank_code: String,
+    /// The ISO-3166, part 1, 2-character identifier of the country.
+    pub country_code: String,
+    /// The 2-character location code.
+    pub location_code: String,
+    /// The 3-character branch code, if present.
+    pub branch_code: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref IBAN_FORMATS: HashMap<String, IbanFormatInfo> = load_iban_formats_from_json();
+    static ref BBAN_PATTERNS: HashMap<String, Regex> = make_bban_patterns();
+    static ref TERRITORY_LOOKUP: HashMap<String, String> = make_iban_territory_lookup();
+    static ref BIC_PATTERN: Regex =
+        Regex::new("^([A-Z0-9]{4})([A-Z]{2})([A-Z0-9]{2})([A-Z0-9]{3})?$").unwrap();
+}
+
+/// Lookup an `IbanFormatInfo` based on the IBAN country prefix, returning
+/// `None` if the country does not use IBANs.
+pub fn lookup(code: &str) -> Option<&'static IbanFormatInfo> {
+    debug!("banking::lookup: {}", code);
+    IBAN_FORMATS.get(code)
+}
+
+/// Lookup an `IbanFormatInfo` based on the IBAN country prefix, returning a
+/// `LookupError` rather than `None` if the code is malformed or unknown.
+pub fn try_lookup(code: &str) -> Result<&'static IbanFormatInfo, LookupError> {
+    debug!("banking::try_lookup: {}", code);
+    check_alphabetic(
+        code,
+        &[2],
+        |code| code.to_ascii_uppercase(),
+        |code| lookup(code).is_some(),
+    )?;
+    match lookup(code) {
+        Some(info) => Ok(info),
+        None => Err(LookupError::unknown(
+            code,
+            IBAN_FORMATS.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
+/// Return the IBAN format used within the provided country, including
+/// dependent territories that use another country's format, if any.
+pub fn for_country(country: &CountryInfo) -> Option<&'static IbanFormatInfo> {
+    match TERRITORY_LOOKUP.get(&country.short_code) {
+        Some(code) => lookup(code),
+        None => lookup(&country.short_code),
+    }
+}
+
+/// Parse, and validate, an IBAN in electronic or print form, returning
+/// `None` if it is not valid.
+pub fn parse_iban(iban: &str) -> Option<Iban> {
+    let value: String = iban
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+    if value.len() < 5 || !value.is_ascii() {
+        return None;
+    }
+    let country_code = &value[..2];
+    match lookup(country_code) {
+        Some(format)
+            if is_known_country(country_code)
+                && value.len() == format.length
+                && value[2..4].chars().all(|c| c.is_ascii_digit())
+                && BBAN_PATTERNS[country_code].is_match(&value[4..])
+                && check_digits_valid(&value) =>
+        {
+            Some(Iban {
+                country_code: country_code.to_string(),
+                value,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the provided IBAN is valid.
+pub fn is_valid_iban(iban: &str) -> bool {
+    parse_iban(iban).is_some()
+}
+
+/// Parse, and validate, a BIC, returning `None` if it is not valid or if
+/// the country code is not a known country, or the user-assigned code for
+/// Kosovo.
+pub fn parse_bic(bic: &str) -> Option<Bic> {
+    let value = bic.trim().to_uppercase();
+    match BIC_PATTERN.captures(&value) {
+        Some(captures) if is_known_country(&captures[2]) => Some(Bic {
+            bank_code: captures[1].to_string(),
+            country_code: captures[2].to_string(),
+            location_code: captures[3].to_string(),
+            branch_code: captures.get(4).map(|branch| branch.as_str().to_string()),
+            value: value.to_string(),
+        }),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the provided BIC is valid.
+pub fn is_valid_bic(bic: &str) -> bool {
+    parse_bic(bic).is_some()
+}
+
+/// Returns `true` if the provided country participated in the Single Euro
+/// Payments Area on the provided date.
+pub fn is_sepa(country: &CountryInfo, date: &str) -> bool {
+    match group::lookup(SEPA_GROUP) {
+        Some(sepa) => sepa.is_member(country, date),
+        None => false,
+    }
+}
+
+/// Return all the ISO-3166 2-character country codes used as IBAN prefixes.
+pub fn all_codes() -> Vec<String> {
+    IBAN_FORMATS.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Iban {
+    /// Return the 2 check digits.
+    pub fn check_digits(&self) -> &str {
+        &self.value[2..4]
+    }
+
+    /// Return the country-specific Basic Bank Account Number.
+    pub fn bban(&self) -> &str {
+        &self.value[4..]
+    }
+
+    /// Return the country identified by the IBAN prefix, this is `None`
+    /// for the user-assigned code `XK`.
+    pub fn country(&self) -> Option<&'static CountryInfo> {
+        country::try_lookup(&self.country_code).ok()
+    }
+
+    /// Return the IBAN format of the country identified by the IBAN prefix.
+    pub fn format(&self) -> Option<&'static IbanFormatInfo> {
+        lookup(&self.country_code)
+    }
+
+    /// Return the IBAN in print form, in groups of four characters.
+    pub fn to_print_format(&self) -> String {
+        self.value
+            .as_bytes()
+            .chunks(4)
+            .map(|chunk| std::str::from_utf8(chunk).unwrap())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Returns `true` if the IBAN's country participated in the Single Euro
+    /// Payments Area on the provided date.
+    pub fn is_sepa(&self, date: &str) -> bool {
+        match self.country() {
+            Some(country) => is_sepa(country, date),
+            None => false,
+        }
+    }
+}
+
+impl IbanFormatInfo {
+    /// Return the currency of the country, if it is a registered currency.
+    pub fn currency(&self) -> Option<&'static CurrencyInfo> {
+        currency::lookup_by_alpha(&self.currency_code)
+    }
+}
+
+impl Bic {
+    /// Return the country identified by the BIC, this is `None` for the
+    /// user-assigned code `XK`.
+    pub fn country(&self) -> Option<&'static CountryInfo> {
+        country::try_lookup(&self.country_code).ok()
+    }
+
+    /// Returns `true` if this BIC identifies a bank's primary office; that is
+    /// it has no branch code, or the branch code is `XXX`.
+    pub fn is_primary_office(&self) -> bool {
+        match &self.branch_code {
+            Some(branch_code) => branch_code == "XXX",
+            None => true,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const SEPA_GROUP: &str = "SEPA";
+
+fn is_known_country(code: &str) -> bool {
+    country::try_lookup(code).is_ok()
+        || match country::lookup_reserved(code) {
+            Some(reserved) => reserved.status == AssignmentStatus::UserAssigned,
+            None => false,
+        }
+}
+
+fn check_digits_valid(iban: &str) -> bool {
+    let rearranged = iban[4..].chars().chain(iban[..4].chars());
+    let mut remainder: u32 = 0;
+    for c in rearranged {
+        match c.to_digit(36) {
+            Some(digit) if digit < 10 => remainder = (remainder * 10 + digit) % 97,
+            Some(digit) => remainder = (remainder * 100 + digit) % 97,
+            None => return false,
+        }
+    }
+    remainder == 1
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_iban_formats_from_json() -> HashMap<String, IbanFormatInfo> {
+    info!("load_iban_formats_from_json - loading JSON");
+    let raw_data = include_bytes!("data/iban_formats.json");
+    let format_map: HashMap<String, IbanFormatInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_iban_formats_from_json - loaded {} IBAN formats",
+        format_map.len()
+    );
+    format_map
+}
+
+fn make_bban_patterns() -> HashMap<String, Regex> {
+    info!("make_bban_patterns - create from IBAN_FORMATS");
+    let pattern_map: HashMap<String, Regex> = IBAN_FORMATS
+        .values()
+        .map(|info| {
+            (
+                info.country_code.to_string(),
+                Regex::new(&format!("^(?:{})$", info.bban_pattern)).unwrap(),
+            )
+        })
+        .collect();
+    info!(
+        "make_bban_patterns - compiled {} patterns",
+        pattern_map.len()
+    );
+    pattern_map
+}
+
+fn make_iban_territory_lookup() -> HashMap<String, String> {
+    info!("make_iban_territory_lookup - create from IBAN_FORMATS");
+    let mut lookup_map: HashMap<String, String> = HashMap::new();
+    for info in IBAN_FORMATS.values() {
+        for territory in &info.territories {
+            lookup_map.insert(territory.to_string(), info.country_code.to_string());
+        }
+    }
+    info!(
+        "make_iban_territory_lookup - mapped {} territories",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::LookupErrorKind;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_iban_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"DE".to_string()));
+        assert!(!codes.contains(&"US".to_string()));
+    }
+
+    #[test]
+    fn test_good_iban_format() {
+        let jersey = country::lookup("JEY").unwrap();
+        match for_country(jersey) {
+            None => panic!("was expecting an IBAN format"),
+            Some(format) => {
+                assert_eq!(format.country_code, "GB");
+                assert_eq!(format.length, 22);
+                assert_eq!(format.bban_format, "4!a6!n8!n");
+            }
+        }
+        let us = country::lookup("USA").unwrap();
+        assert!(for_country(us).is_none());
+    }
+
+    #[test]
+    fn test_all_examples_valid() {
+        for code in all_codes() {
+            let format = lookup(&code).unwrap();
+            let iban = parse_iban(&format.example).unwrap();
+            assert_eq!(iban.country_code, code);
+        }
+    }
+
+    #[test]
+    fn test_parse_iban() {
+        let iban = parse_iban("DE89 3704 0044 0532 0130 00").unwrap();
+        assert_eq!(iban.value, "DE89370400440532013000");
+        assert_eq!(iban.check_digits(), "89");
+        assert_eq!(iban.bban(), "370400440532013000");
+        assert_eq!(iban.country().unwrap().code, "DEU");
+        assert_eq!(iban.format().unwrap().currency_code, "EUR");
+
+        // bad checksum, length, structure, and country
+        assert!(!is_valid_iban("DE88370400440532013000"));
+        assert!(!is_valid_iban("DE8937040044053201300"));
+        assert!(!is_valid_iban("GB29NWBK6016133192681X"));
+        assert!(!is_valid_iban("US64SVBKUS6S3300958879"));
+        assert!(!is_valid_iban("DE"));
+        assert!(!is_valid_iban("ÄÖ89370400440532013000"));
+    }
+
+    #[test]
+    fn test_kosovo_iban() {
+        let iban = parse_iban("XK05 1212 0123 4567 8906").unwrap();
+        assert_eq!(iban.country_code, "XK");
+        assert!(iban.country().is_none());
+        assert!(!iban.is_sepa("2024-01-01"));
+        assert_eq!(lookup("XK").unwrap().length, 20);
+        assert_eq!(
+            country::lookup_reserved(&iban.country_code).unwrap().name,
+            "Kosovo"
+        );
+        assert!(parse_bic("RBKOXKPR").unwrap().country().is_none());
+    }
+
+    #[test]
+    fn test_iban_currency() {
+        assert_eq!(
+            lookup("GB").unwrap().currency().unwrap().alphabetic_code,
+            "GBP"
+        );
+        assert_eq!(
+            lookup("XK").unwrap().currency().unwrap().alphabetic_code,
+            "EUR"
+        );
+        for code in all_codes() {
+            assert!(lookup(&code).unwrap().currency().is_some(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_parse_bic() {
+        let bic = parse_bic("deutdeff500").unwrap();
+        assert_eq!(bic.bank_code, "DEUT");
+        assert_eq!(bic.country_code, "DE");
+        assert_eq!(bic.location_code, "FF");
+        assert_eq!(bic.branch_code, Some("500".to_string()));
+        assert!(!bic.is_primary_office());
+        assert!(parse_bic("NWBKGB2L").unwrap().is_primary_office());
+
+        assert!(!is_valid_bic("NWBKGB2"));
+        assert!(!is_valid_bic("NWBKXX2L"));
+        assert!(!is_valid_bic("NWBKGB2LX"));
+    }
+
+    #[test]
+    fn test_is_sepa() {
+        assert!(parse_iban("CH9300762011623852957")
+            .unwrap()
+            .is_sepa("2024-01-01"));
+        assert!(!parse_iban("TR330006100519786457841326")
+            .unwrap()
+            .is_sepa("2024-01-01"));
+        assert!(is_sepa(country::lookup("GBR").unwrap(), "2024-01-01"));
+    }
+
+    #[test]
+    fn test_try_banking_lookup() {
+        assert_eq!(try_lookup("DE").unwrap().length, 22);
+        assert_eq!(
+            try_lookup("de").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("D").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("QQ").unwrap_err().kind(),
+            LookupErrorKind::Unknown
+        );
+    }
+}
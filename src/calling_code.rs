@@ -0,0 +1,279 @@
+/*!
+International telephone calling codes for countries.
+
+ITU-T Recommendation E.164 defines the international public telecommunication
+numbering plan. Each country, or group of countries, is assigned a country
+calling code of one to three digits, and an international number is written
+as `+`, the calling code, and the national significant number, for example
+`+49 30 1234567`.
+
+Some calling codes are shared by more than one country. In these cases one
+country is the _primary_ user of the code, and the others are identified by
+the area code at the start of the national number. For example `+1` is the
+North American Numbering Plan, used primarily by the United States, where
+Canada, Jamaica, and many others are identified by their 3-digit area codes;
+similarly `+44` is used by the United Kingdom, where Guernsey, Jersey, and
+the Isle of Man are identified by area codes such as `1481`. A few codes,
+such as `+590`, are shared with no area codes to tell the countries apart.
+
+## Source - ITU-T E.164
+
+The data used here was compiled from the
+[ITU](https://www.itu.int/pub/T-SP-E.164D) list of assigned country codes,
+and the national numbering plans published by the ITU.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::{self, CountryInfo};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A representation of the telephone numbering details for a country.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CallingCodeInfo {
+    /// The ISO-3166, part 1, 2-character identifier of the country.
+    pub country_code: String,
+    /// The E.164 country calling code, including the leading `+`.
+    pub calling_code: String,
+    /// Where the calling code is shared, the prefixes of the national number
+    /// that identify this country. This is empty for the primary user of the
+    /// calling code.
+    pub area_codes: Vec<String>,
+    /// The prefix dialled from this country to make an international call,
+    /// for example `00`, or `011` within the NANP.
+    pub international_prefix: String,
+    /// The prefix dialled from within this country to make a national call,
+    /// if any, for example `0` in Germany, or `1` within the NANP.
+    pub trunk_prefix: Option<String>,
+}
+
+/// An international telephone number split into it's components.
+#[derive(Debug)]
+pub struct E164Number {
+    /// The E.164 country calling code, including the leading `+`.
+    pub calling_code: String,
+    /// The national significant number, the digits following the calling
+    /// code.
+    pub national_number: String,
+    /// The countries the number may belong to; this will contain more than
+    /// one country only where the calling code is shared and the national
+    /// number does not identify a single country.
+    pub countries: Vec<&'static CountryInfo>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref CALLING_CODES: Vec<CallingCodeInfo> = load_calling_codes_from_json();
+    static ref CODE_LOOKUP: HashMap<String, Vec<usize>> = make_calling_code_lookup();
+}
+
+/// Return the calling codes used by the provided country, this is usually a
+/// single code, and is empty for uninhabited territories.
+pub fn calling_codes(country: &CountryInfo) -> Vec<&'static CallingCodeInfo> {
+    CALLING_CODES
+        .iter()
+        .filter(|info| info.country_code == country.short_code)
+        .collect()
+}
+
+/// Return the countries that use the calling code, which may be specified
+/// with or without the leading `+`. The primary user of the code is
+/// returned first, followed by any other countries sharing the code.
+pub fn countries_for_calling_code(calling_code: &str) -> Vec<&'static CountryInfo> {
+    lookup(calling_code)
+        .iter()
+        .filter_map(|info| country::lookup(&info.country_code))
+        .collect()
+}
+
+/// Return the numbering details of all countries using the calling code,
+/// which may be specified with or without the leading `+`. The primary user
+/// of the code is returned first.
+pub fn lookup(calling_code: &str) -> Vec<&'static CallingCodeInfo> {
+    debug!("calling_code::lookup: {}", calling_code);
+    let calling_code = calling_code.trim_start_matches('+');
+    match CODE_LOOKUP.get(calling_code) {
+        Some(indices) => indices.iter().map(|i| &CALLING_CODES[*i]).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Returns `true` if the calling code is used by more than one country.
+pub fn is_shared(calling_code: &str) -> bool {
+    lookup(calling_code).len() > 1
+}
+
+/// Split an international number, such as `+44 1534 123456`, into it's
+/// calling code and national number, and determine the country it belongs to.
+/// Spaces, hyphens, periods, and parentheses are ignored. Returns `None` if
+/// the number does not start with `+`, contains other characters, is longer
+/// than the 15 digits allowed by E.164, or has an unknown calling code.
+pub fn split_number(number: &str) -> Option<E164Number> {
+    debug!("calling_code::split_number: {}", number);
+    if !number.starts_with('+') {
+        return None;
+    }
+    let digits: String = number[1..]
+        .chars()
+        .filter(|c| !(c.is_whitespace() || "-.()".contains(*c)))
+        .collect();
+    if digits.is_empty() || digits.len() > 15 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    for length in 1..=3.min(digits.len() - 1) {
+        let (calling_code, national_number) = digits.split_at(length);
+        let infos = lookup(calling_code);
+        if !infos.is_empty() {
+            return Some(E164Number {
+                calling_code: format!("+{}", calling_code),
+                national_number: national_number.to_string(),
+                countries: countries_for_national_number(&infos, national_number),
+            });
+        }
+    }
+    None
+}
+
+/// Return all the assigned calling codes, including the leading `+`.
+pub fn all_codes() -> Vec<String> {
+    CODE_LOOKUP
+        .keys()
+        .map(|code| format!("+{}", code))
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn countries_for_national_number(
+    infos: &[&'static CallingCodeInfo],
+    national_number: &str,
+) -> Vec<&'static CountryInfo> {
+    let area_code_length = |info: &CallingCodeInfo| {
+        info.area_codes
+            .iter()
+            .filter(|area_code| national_number.starts_with(area_code.as_str()))
+            .map(|area_code| area_code.len())
+            .max()
+            .unwrap_or(0)
+    };
+    let longest = infos.iter().map(|info| area_code_length(info)).max();
+    infos
+        .iter()
+        .filter(|info| match longest {
+            Some(0) | None => info.area_codes.is_empty(),
+            Some(length) => area_code_length(info) == length,
+        })
+        .filter_map(|info| country::lookup(&info.country_code))
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_calling_codes_from_json() -> Vec<CallingCodeInfo> {
+    info!("load_calling_codes_from_json - loading JSON");
+    let raw_data = include_bytes!("data/calling_codes.json");
+    let calling_codes: Vec<CallingCodeInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_calling_codes_from_json - loaded {} countries",
+        calling_codes.len()
+    );
+    calling_codes
+}
+
+fn make_calling_code_lookup() -> HashMap<String, Vec<usize>> {
+    info!("make_calling_code_lookup - create from CALLING_CODES");
+    let mut lookup_map: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, info) in CALLING_CODES.iter().enumerate() {
+        lookup_map
+            .entry(info.calling_code.trim_start_matches('+').to_string())
+            .or_default()
+            .push(index);
+    }
+    for indices in lookup_map.values_mut() {
+        indices.sort_by_key(|i| {
+            let info = &CALLING_CODES[*i];
+            (!info.area_codes.is_empty(), info.country_code.to_string())
+        });
+    }
+    info!(
+        "make_calling_code_lookup - mapped {} calling codes",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_calling_code_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"+44".to_string()));
+    }
+
+    #[test]
+    fn test_calling_codes_for_country() {
+        let germany = country::lookup("DE").unwrap();
+        let codes = calling_codes(germany);
+        assert_eq!(codes.len(), 1);
+        assert_eq!(codes[0].calling_code, "+49");
+        assert_eq!(codes[0].international_prefix, "00");
+        assert_eq!(codes[0].trunk_prefix, Some("0".to_string()));
+
+        let canada = country::lookup("CA").unwrap();
+        let codes = calling_codes(canada);
+        assert_eq!(codes[0].calling_code, "+1");
+        assert!(codes[0].area_codes.contains(&"416".to_string()));
+    }
+
+    #[test]
+    fn test_countries_for_calling_code() {
+        let codes: Vec<&str> = countries_for_calling_code("+44")
+            .iter()
+            .map(|c| c.short_code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["GB", "GG", "IM", "JE"]);
+        assert!(is_shared("+44"));
+        assert!(!is_shared("49"));
+        assert!(countries_for_calling_code("+999").is_empty());
+    }
+
+    #[test]
+    fn test_split_number() {
+        let number = split_number("+44 1534 123456").unwrap();
+        assert_eq!(number.calling_code, "+44");
+        assert_eq!(number.national_number, "1534123456");
+        assert_eq!(number.countries.len(), 1);
+        assert_eq!(number.countries[0].short_code, "JE");
+
+        let number = split_number("+1 (416) 555-0100").unwrap();
+        assert_eq!(number.countries[0].short_code, "CA");
+
+        let number = split_number("+1 212 555 0100").unwrap();
+        assert_eq!(number.countries[0].short_code, "US");
+
+        let number = split_number("+590 590 123456").unwrap();
+        assert_eq!(number.countries.len(), 3);
+
+        assert!(split_number("0044 1534 123456").is_none());
+        assert!(split_number("+44 1534 ABC").is_none());
+    }
+}
@@ -22,6 +22,8 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::{LookupError, LookupErrorKind};
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -50,12 +52,47 @@ lazy_static! {
 }
 
 /// Lookup a `CodesetInfo` based on it's name, returning `None` if the name
-/// does not exist in the current IANA data set.
+/// does not exist in the current IANA data set. This will panic if the name
+/// is empty; see `try_lookup`.
 pub fn lookup(name: &str) -> Option<&'static CodesetInfo> {
     assert!(name.len() > 0, "codeset name may not be empty");
     CODESETS.get(name)
 }
 
+/// Lookup a `CodesetInfo` based on it's name, returning a `LookupError`
+/// rather than panicking if the name is empty. Where the name is one of the
+/// registered aliases of a code set, such as `utf8`, or differs only in case,
+/// the registered name is suggested.
+pub fn try_lookup(name: &str) -> Result<&'static CodesetInfo, LookupError> {
+    debug!("codeset::try_lookup: {}", name);
+    if name.trim().is_empty() {
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            name,
+            Vec::new(),
+        ));
+    }
+    if let Some(codeset) = CODESETS.get(name) {
+        return Ok(codeset);
+    }
+    match CODESETS.values().find(|codeset| {
+        codeset
+            .also_known_as
+            .iter()
+            .any(|alias| alias.eq_ignore_ascii_case(name))
+    }) {
+        Some(codeset) => Err(LookupError::new(
+            LookupErrorKind::Unknown,
+            name,
+            vec![codeset.name.to_string()],
+        )),
+        None => Err(LookupError::unknown(
+            name,
+            CODESETS.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
 /// Return all the registered script names.
 pub fn all_names() -> Vec<String> {
     CODESETS.keys().cloned().collect()
@@ -100,4 +137,18 @@ mod tests {
             Some(_) => panic!("was expecting a None in response"),
         }
     }
+
+    #[test]
+    fn test_try_codeset_lookup() {
+        assert_eq!(try_lookup("UTF-8").unwrap().name, "UTF-8");
+        assert_eq!(
+            try_lookup("").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(try_lookup("utf-8").unwrap_err().suggestions()[0], "UTF-8");
+        assert_eq!(
+            try_lookup("UTF-99").unwrap_err().kind(),
+            LookupErrorKind::Unknown
+        );
+    }
 }
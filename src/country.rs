@@ -8,15 +8,30 @@ and subdivisions. However, it does not define the names of countries
 Bulletin Country Names and the Country and Region Codes for Statistical
 Use maintained by the United Nations Statistics Divisions).
 
+Codes that have been withdrawn from ISO 3166-1 are not returned by `lookup`,
+see the [`former_country`](../former_country/index.html) module for these.
+
 ## Source - ISO 3166
 
 The data used here is taken from the page
 [Github](https://github.com/lukes/ISO-3166-Countries-with-Regional-Codes).
+The English short, official, and French names are taken from the Debian
+[iso-codes](https://salsa.debian.org/iso-codes-team/iso-codes) project.
 */
 
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::currency::CurrencyInfo;
+use crate::display_name::{self, NameForm};
+use crate::error::{check_alphabetic, LookupError, LookupErrorKind};
+use crate::former_country;
+use crate::postal_code;
+use crate::subdivision;
+use crate::tld;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -41,6 +56,63 @@ pub struct CountryInfo {
     /// The optional numeric code for the `RegionInfo` that represents the
     /// intermediate region.
     pub intermediate_region_code: Option<u16>,
+    /// The ISO-3166 short name, in English, of the country.
+    pub name: String,
+    /// The official, or full, name, in English, of the country if it
+    /// differs from the short name.
+    pub official_name: Option<String>,
+    /// The short name, in French, of the country.
+    pub french_name: String,
+}
+
+/// The assignment status of an ISO-3166-1 code element, as defined by the
+/// ISO 3166 Maintenance Agency.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentStatus {
+    /// Officially assigned to a country, this code will be returned by
+    /// `lookup`.
+    Official,
+    /// Reserved on request for a particular use, such as `EU` for the
+    /// European Union, or `UK` for the United Kingdom.
+    ExceptionallyReserved,
+    /// Deleted from ISO-3166-1 but reserved for a transitional period, such
+    /// as `YU` for Yugoslavia.
+    TransitionallyReserved,
+    /// Used in other coding systems, such as vehicle registration, and
+    /// reserved to avoid conflicts, such as `RA` for Argentina.
+    IndeterminatelyReserved,
+    /// Free for users to assign, such as `ZZ`, or the `XA` to `XZ` range;
+    /// `XK` is commonly used for Kosovo.
+    UserAssigned,
+    /// Not assigned, or reserved, in any way.
+    Unassigned,
+}
+
+/// A representation of a reserved, or commonly used user-assigned, country
+/// code.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ReservedCountryInfo {
+    /// The reserved 2-character, or 3-character, identifier.
+    pub code: String,
+    /// The reason this code is reserved.
+    pub status: AssignmentStatus,
+    /// The name of the country, or other entity, the code is reserved for.
+    pub name: String,
+}
+
+/// Denotes how a name passed to `lookup_by_name` was matched to a country.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum NameMatch {
+    /// The name is one of the ISO-3166 short names, or the official name,
+    /// of the country.
+    Exact,
+    /// The name is a common name, abbreviation, or other well-known alias
+    /// for the country.
+    Alias,
+    /// The name is one formerly used by the country.
+    Former,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -50,10 +122,14 @@ pub struct CountryInfo {
 lazy_static! {
     static ref COUNTRIES: HashMap<String, CountryInfo> = load_countries_from_json();
     static ref LOOKUP: HashMap<String, String> = make_country_lookup();
+    static ref NUMERIC_LOOKUP: HashMap<u16, String> = make_country_numeric_lookup();
+    static ref NAME_LOOKUP: HashMap<String, (String, NameMatch)> = load_country_names_from_json();
+    static ref RESERVED: HashMap<String, ReservedCountryInfo> = load_reserved_countries_from_json();
 }
 
 /// Lookup a `CountryInfo` based on it's ISO-3166 identifier, returning
-/// `None` if the name does not exist in the current ISO data set.
+/// `None` if the name does not exist in the current ISO data set. This will
+/// panic if the code is not 2, or 3, characters long; see `try_lookup`.
 pub fn lookup(code: &str) -> Option<&'static CountryInfo> {
     debug!("lookup_country: {}", code);
     assert!(
@@ -79,15 +155,318 @@ pub fn lookup(code: &str) -> Option<&'static CountryInfo> {
     }
 }
 
+/// Lookup a `CountryInfo` based on it's ISO-3166 identifier, returning a
+/// `LookupError` rather than panicking if the code is malformed. The error
+/// distinguishes codes that have been withdrawn, suggesting the countries
+/// that succeeded them, and codes that are reserved, see `status`.
+pub fn try_lookup(code: &str) -> Result<&'static CountryInfo, LookupError> {
+    debug!("country::try_lookup: {}", code);
+    check_alphabetic(
+        code,
+        &[2, 3],
+        |code| code.to_ascii_uppercase(),
+        |code| lookup(code).is_some(),
+    )?;
+    if let Some(country) = lookup(code) {
+        return Ok(country);
+    }
+    let same_length = |country: &'static CountryInfo| {
+        if code.len() == 2 {
+            country.short_code.to_string()
+        } else {
+            country.code.to_string()
+        }
+    };
+    if let Some(former) = former_country::lookup(code) {
+        let successors = former
+            .successor_codes
+            .iter()
+            .filter_map(|successor| lookup(successor))
+            .map(same_length)
+            .collect();
+        return Err(LookupError::new(
+            LookupErrorKind::Withdrawn,
+            code,
+            successors,
+        ));
+    }
+    match status(code) {
+        AssignmentStatus::Unassigned => Err(LookupError::unknown(
+            code,
+            COUNTRIES.values().map(|country| {
+                if code.len() == 2 {
+                    country.short_code.as_str()
+                } else {
+                    country.code.as_str()
+                }
+            }),
+        )),
+        _ => {
+            let suggestions = match lookup_reserved(code) {
+                Some(reserved) => lookup_by_name(&reserved.name)
+                    .map(|(country, _)| same_length(country))
+                    .into_iter()
+                    .collect(),
+                None => Vec::new(),
+            };
+            Err(LookupError::new(
+                LookupErrorKind::Reserved,
+                code,
+                suggestions,
+            ))
+        }
+    }
+}
+
+/// Lookup a `CountryInfo` based on it's ISO-3166 numeric identifier,
+/// returning `None` if the code does not exist in the current ISO data set.
+pub fn lookup_by_numeric(numeric_code: u16) -> Option<&'static CountryInfo> {
+    debug!("lookup_country_by_numeric: {}", numeric_code);
+    match NUMERIC_LOOKUP.get(&numeric_code) {
+        Some(v) => lookup(v),
+        None => None,
+    }
+}
+
+/// Lookup a `CountryInfo` based on it's name, returning `None` if the name
+/// is not known. Names are compared ignoring case, diacritics, punctuation,
+/// and the word "the", so that "Cote d'Ivoire" and "Côte d’Ivoire" are the
+/// same, as are "Gambia" and "The Gambia".
+/// The returned `NameMatch` denotes whether the name was matched exactly or
+/// through an alias or former name.
+pub fn lookup_by_name(name: &str) -> Option<(&'static CountryInfo, NameMatch)> {
+    debug!("lookup_country_by_name: {}", name);
+    match NAME_LOOKUP.get(&normalize_name(name)) {
+        Some((code, name_match)) => match lookup(code) {
+            Some(country) => Some((country, *name_match)),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+/// Lookup a `CountryInfo` based on a flag emoji, returning `None` if the
+/// string is not a flag, or the flag is not for a registered country. Both
+/// regional indicator pairs, such as 🇩🇪, and subdivision tag sequences,
+/// such as the flag of Scotland, are recognized; in the latter case the
+/// country containing the subdivision is returned.
+pub fn lookup_by_flag(flag: &str) -> Option<&'static CountryInfo> {
+    debug!("lookup_country_by_flag: {}", flag);
+    let indicators: Vec<char> = flag.chars().collect();
+    if indicators.len() == 2 && indicators.iter().all(|c| is_regional_indicator(*c)) {
+        let code: String = indicators
+            .iter()
+            .map(|c| (b'A' + (*c as u32 - REGIONAL_INDICATOR_A) as u8) as char)
+            .collect();
+        lookup(&code)
+    } else {
+        match subdivision::lookup_by_flag(flag) {
+            Some(subdivision) => lookup(&subdivision.country_code),
+            None => None,
+        }
+    }
+}
+
+/// Lookup a `CountryInfo` based on the country code top-level domain of the
+/// provided domain name, for example `.de`, `www.example.co.uk`, or `.рф`,
+/// returning `None` if the domain is not a ccTLD, or is not delegated for a
+/// single registered country (such as `.eu` or `.su`).
+pub fn for_tld(domain: &str) -> Option<&'static CountryInfo> {
+    debug!("lookup_country_for_tld: {}", domain);
+    match tld::lookup(domain) {
+        Some(info) => match &info.country_code {
+            Some(country_code) => lookup(country_code),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+/// Lookup a `CountryInfo` based on the region of a locale identifier, in
+/// either BCP 47 (`en-US`, `zh-Hant-TW`) or POSIX (`en_US.UTF-8`) form,
+/// returning `None` if the locale has no region, or the region is not a
+/// country (for example `es-419`, Latin America).
+pub fn for_locale(locale: &str) -> Option<&'static CountryInfo> {
+    debug!("lookup_country_for_locale: {}", locale);
+    let locale = locale
+        .split(|c| c == '.' || c == '@')
+        .next()
+        .unwrap_or_default();
+    let region = locale
+        .split(|c| c == '-' || c == '_')
+        .skip(1)
+        .find(|subtag| {
+            (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        });
+    match region {
+        Some(region) if region.len() == 2 => lookup(&region.to_ascii_uppercase()),
+        Some(region) => match region.parse::<u16>() {
+            Ok(numeric_code) => lookup_by_numeric(numeric_code),
+            Err(_) => None,
+        },
+        None => None,
+    }
+}
+
+/// Lookup a `ReservedCountryInfo` based on it's 2-character, or 3-character,
+/// identifier, returning `None` if the code is not reserved.
+pub fn lookup_reserved(code: &str) -> Option<&'static ReservedCountryInfo> {
+    debug!("lookup_reserved_country: {}", code);
+    RESERVED.get(code)
+}
+
+/// Return the assignment status of the 2-character, or 3-character,
+/// identifier. Unlike `lookup`, any string is accepted, and
+/// `AssignmentStatus::Unassigned` is returned for anything that is not a
+/// valid code.
+pub fn status(code: &str) -> AssignmentStatus {
+    if !(code.len() == 2 || code.len() == 3) || !code.chars().all(|c| c.is_ascii_uppercase()) {
+        return AssignmentStatus::Unassigned;
+    }
+    if lookup(code).is_some() {
+        AssignmentStatus::Official
+    } else if let Some(reserved) = lookup_reserved(code) {
+        reserved.status
+    } else if is_user_assigned(code) {
+        AssignmentStatus::UserAssigned
+    } else {
+        AssignmentStatus::Unassigned
+    }
+}
+
+/// Return all the countries using the provided currency, sorted by code.
+pub fn countries_using(currency: &CurrencyInfo) -> Vec<&'static CountryInfo> {
+    currency
+        .country_codes
+        .iter()
+        .filter_map(|code| lookup(code))
+        .collect()
+}
+
+/// Validate, and normalize, a postal code for the provided country; see
+/// [`postal_code`](../postal_code/index.html) for the normalization applied.
+/// Returns the normalized postal code, or `None` if it is not valid. An
+/// empty postal code is valid only where the country does not require one,
+/// and it is the only valid value where the country does not use postal
+/// codes.
+pub fn validate_postal_code(country: &CountryInfo, postal_code: &str) -> Option<String> {
+    debug!("validate_postal_code: {}, {}", country.code, postal_code);
+    let format = postal_code::for_country(country);
+    if postal_code.trim().is_empty() {
+        match format {
+            Some(format) if format.required => None,
+            _ => Some(String::new()),
+        }
+    } else {
+        match format {
+            Some(format) => format.normalize(postal_code),
+            None => None,
+        }
+    }
+}
+
+/// Return the standard name of the identified country, in the language of the
+/// provided locale identifier, for example `Allemagne` for `DE` in `fr`;
+/// see [`display_name`](../display_name/index.html). Returns `None` if the
+/// code is not a registered country or names are not available in the
+/// language.
+pub fn display_name(code: &str, in_language: &str) -> Option<&'static str> {
+    display_name_with_form(code, in_language, NameForm::Standard)
+}
+
+/// Return the name of the identified country, in the provided form, in the
+/// language of the provided locale identifier; for example `UK` is the
+/// short form for `GB` in `en`.
+pub fn display_name_with_form(
+    code: &str,
+    in_language: &str,
+    form: NameForm,
+) -> Option<&'static str> {
+    if !(code.len() == 2 || code.len() == 3) {
+        return None;
+    }
+    match lookup(code) {
+        Some(country) => display_name::country_name(&country.code, in_language, form),
+        None => None,
+    }
+}
+
 /// Return all the registered ISO-3166 2-character country codes.
 pub fn all_codes() -> Vec<String> {
     COUNTRIES.keys().cloned().collect()
 }
 
+/// Return all the registered ISO-3166 numeric country codes.
+pub fn all_numeric_codes() -> Vec<u16> {
+    NUMERIC_LOOKUP.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl CountryInfo {
+    /// Return the flag emoji for this country, constructed as the pair of
+    /// Unicode regional indicator symbols corresponding to `short_code`.
+    /// For example, Germany (`DE`) returns "🇩🇪".
+    pub fn flag_emoji(&self) -> String {
+        self.short_code
+            .chars()
+            .filter_map(|c| std::char::from_u32(REGIONAL_INDICATOR_A + (c as u32 - 'A' as u32)))
+            .collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const REGIONAL_INDICATOR_A: u32 = 0x1F1E6;
+
+fn is_regional_indicator(c: char) -> bool {
+    let c = c as u32;
+    c >= REGIONAL_INDICATOR_A && c < REGIONAL_INDICATOR_A + 26
+}
+
+pub(crate) fn normalize_name(name: &str) -> String {
+    let folded: String = name
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_lowercase().next().unwrap_or(c)
+            } else {
+                ' '
+            }
+        })
+        .collect();
+    folded
+        .split_whitespace()
+        .filter(|word| *word != "the")
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn is_user_assigned(code: &str) -> bool {
+    let prefix = &code[..2];
+    prefix == "AA"
+        || prefix == "ZZ"
+        || (prefix >= "QM" && prefix <= "QZ")
+        || prefix.starts_with('X')
+}
+
 // ------------------------------------------------------------------------------------------------
 // Generated Data
 // ------------------------------------------------------------------------------------------------
 
+#[derive(Deserialize)]
+struct CountryName {
+    name: String,
+    code: String,
+    kind: NameMatch,
+}
+
 fn load_countries_from_json() -> HashMap<String, CountryInfo> {
     info!("load_countries_from_json - loading JSON");
     let raw_data = include_bytes!("data/countries.json");
@@ -112,6 +491,48 @@ fn make_country_lookup() -> HashMap<String, String> {
     lookup_map
 }
 
+fn make_country_numeric_lookup() -> HashMap<u16, String> {
+    info!("load_country_numeric_lookup - create from COUNTRIES");
+    let mut lookup_map: HashMap<u16, String> = HashMap::new();
+    for country in COUNTRIES.values() {
+        lookup_map.insert(country.country_code, country.code.to_string());
+    }
+    info!(
+        "load_country_numeric_lookup - mapped {} countries",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+fn load_country_names_from_json() -> HashMap<String, (String, NameMatch)> {
+    info!("load_country_names_from_json - loading JSON");
+    let raw_data = include_bytes!("data/country_names.json");
+    let names: Vec<CountryName> = serde_json::from_slice(raw_data).unwrap();
+    let mut lookup_map: HashMap<String, (String, NameMatch)> = HashMap::new();
+    for name in names {
+        lookup_map
+            .entry(normalize_name(&name.name))
+            .or_insert((name.code, name.kind));
+    }
+    info!(
+        "load_country_names_from_json - mapped {} names",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+fn load_reserved_countries_from_json() -> HashMap<String, ReservedCountryInfo> {
+    info!("load_reserved_countries_from_json - loading JSON");
+    let raw_data = include_bytes!("data/reserved_countries.json");
+    let reserved_map: HashMap<String, ReservedCountryInfo> =
+        serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_reserved_countries_from_json - loaded {} codes",
+        reserved_map.len()
+    );
+    reserved_map
+}
+
 // ------------------------------------------------------------------------------------------------
 // Unit Tests
 // ------------------------------------------------------------------------------------------------
@@ -134,10 +555,28 @@ mod tests {
             Some(country) => {
                 assert_eq!(country.short_code, "DE");
                 assert_eq!(country.country_code, 276);
+                assert_eq!(country.name, "Germany");
+                assert_eq!(
+                    country.official_name,
+                    Some("Federal Republic of Germany".to_string())
+                );
+                assert_eq!(country.french_name, "Allemagne");
             }
         }
     }
 
+    #[test]
+    fn test_country_names() {
+        let uk = lookup("GBR").unwrap();
+        assert_eq!(uk.name, "United Kingdom");
+        assert_eq!(
+            uk.official_name,
+            Some("United Kingdom of Great Britain and Northern Ireland".to_string())
+        );
+        assert_eq!(lookup("TUR").unwrap().name, "Türkiye");
+        assert_eq!(lookup("HUN").unwrap().official_name, None);
+    }
+
     #[test]
     fn test_good_country_short_code() {
         match lookup("DE") {
@@ -149,6 +588,163 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_country_numeric_codes() {
+        let numerics = all_numeric_codes();
+        assert_eq!(numerics.len(), all_codes().len());
+    }
+
+    #[test]
+    fn test_good_country_numeric_code() {
+        match lookup_by_numeric(276) {
+            None => panic!("was expecting a country"),
+            Some(country) => {
+                assert_eq!(country.code, "DEU");
+                assert_eq!(country.short_code, "DE");
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_country_numeric_code() {
+        match lookup_by_numeric(0) {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+    }
+
+    #[test]
+    fn test_country_by_name() {
+        let tests = [
+            ("Germany", "DEU", NameMatch::Exact),
+            ("UK", "GBR", NameMatch::Alias),
+            ("Great Britain", "GBR", NameMatch::Alias),
+            ("Türkiye", "TUR", NameMatch::Exact),
+            ("turkey", "TUR", NameMatch::Exact),
+            ("Cote d’Ivoire", "CIV", NameMatch::Exact),
+            ("Ivory Coast", "CIV", NameMatch::Alias),
+            ("Korea, Republic of", "KOR", NameMatch::Exact),
+            ("Korea (the Republic Of)", "KOR", NameMatch::Exact),
+            ("The Netherlands", "NLD", NameMatch::Exact),
+            ("SWAZILAND", "SWZ", NameMatch::Former),
+        ];
+        for (name, code, name_match) in tests.iter() {
+            match lookup_by_name(name) {
+                None => panic!("was expecting a country for {}", name),
+                Some((country, matched)) => {
+                    assert_eq!(country.code, *code);
+                    assert_eq!(matched, *name_match);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_countries_using() {
+        let euro = crate::currency::lookup_by_alpha("EUR").unwrap();
+        let countries = countries_using(euro);
+        assert!(countries.iter().any(|c| c.code == "DEU"));
+        assert!(countries.iter().any(|c| c.code == "VAT"));
+        assert!(!countries.iter().any(|c| c.code == "GBR"));
+    }
+
+    #[test]
+    fn test_bad_country_name() {
+        match lookup_by_name("Atlantis") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+    }
+
+    #[test]
+    fn test_country_status() {
+        let tests = [
+            ("DE", AssignmentStatus::Official),
+            ("DEU", AssignmentStatus::Official),
+            ("UK", AssignmentStatus::ExceptionallyReserved),
+            ("EU", AssignmentStatus::ExceptionallyReserved),
+            ("YU", AssignmentStatus::TransitionallyReserved),
+            ("RA", AssignmentStatus::IndeterminatelyReserved),
+            ("XK", AssignmentStatus::UserAssigned),
+            ("QT", AssignmentStatus::UserAssigned),
+            ("ZZZ", AssignmentStatus::UserAssigned),
+            ("QA", AssignmentStatus::Official),
+            ("JJ", AssignmentStatus::Unassigned),
+            ("D", AssignmentStatus::Unassigned),
+            ("de", AssignmentStatus::Unassigned),
+        ];
+        for (code, expected) in tests.iter() {
+            assert_eq!(status(code), *expected, "status of {}", code);
+        }
+        assert_eq!(lookup_reserved("XK").unwrap().name, "Kosovo");
+    }
+
+    #[test]
+    fn test_country_flag() {
+        let germany = lookup("DEU").unwrap();
+        assert_eq!(germany.flag_emoji(), "\u{1F1E9}\u{1F1EA}");
+        assert_eq!(lookup_by_flag(&germany.flag_emoji()).unwrap().code, "DEU");
+        let scotland = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}";
+        assert_eq!(lookup_by_flag(scotland).unwrap().code, "GBR");
+        assert!(lookup_by_flag("DE").is_none());
+        assert!(lookup_by_flag("\u{1F1FD}\u{1F1FD}").is_none());
+        assert!(lookup_by_flag("\u{1F3F4}ab\u{E007F}").is_none());
+    }
+
+    #[test]
+    fn test_country_for_tld() {
+        assert_eq!(for_tld(".co.uk").unwrap().code, "GBR");
+        assert_eq!(for_tld("gb").unwrap().code, "GBR");
+        assert_eq!(for_tld("www.example.ac").unwrap().code, "SHN");
+        assert_eq!(for_tld("пример.рф").unwrap().code, "RUS");
+        assert!(for_tld(".eu").is_none());
+        assert!(for_tld(".su").is_none());
+        assert!(for_tld(".com").is_none());
+    }
+
+    #[test]
+    fn test_country_for_locale() {
+        assert_eq!(for_locale("en-US").unwrap().code, "USA");
+        assert_eq!(for_locale("de_CH.UTF-8").unwrap().code, "CHE");
+        assert_eq!(for_locale("zh-Hant-tw").unwrap().code, "TWN");
+        assert_eq!(for_locale("es-484").unwrap().code, "MEX");
+        assert!(for_locale("es-419").is_none());
+        assert!(for_locale("fr").is_none());
+    }
+
+    #[test]
+    fn test_validate_postal_code() {
+        let uk = lookup("GBR").unwrap();
+        assert_eq!(
+            validate_postal_code(uk, "sw1a 1aa"),
+            Some("SW1A 1AA".to_string())
+        );
+        assert_eq!(validate_postal_code(uk, ""), None);
+        assert_eq!(validate_postal_code(uk, "90210"), None);
+
+        let ireland = lookup("IRL").unwrap();
+        assert_eq!(validate_postal_code(ireland, " "), Some(String::new()));
+
+        let hong_kong = lookup("HKG").unwrap();
+        assert_eq!(validate_postal_code(hong_kong, ""), Some(String::new()));
+        assert_eq!(validate_postal_code(hong_kong, "999077"), None);
+    }
+
+    #[test]
+    fn test_country_display_name() {
+        assert_eq!(display_name("DE", "fr"), Some("Allemagne"));
+        assert_eq!(display_name("DEU", "de"), Some("Deutschland"));
+        assert_eq!(display_name("BOL", "fr"), Some("Bolivie"));
+        assert_eq!(
+            display_name_with_form("US", "fr", NameForm::Short),
+            Some("É.-U.")
+        );
+        assert_eq!(display_name("DE", "en"), Some("Germany"));
+        assert_eq!(display_name("DE", "tlh"), None);
+        assert_eq!(display_name("XX", "fr"), None);
+        assert_eq!(display_name("Germany", "fr"), None);
+    }
+
     #[test]
     fn test_bad_country_code() {
         match lookup("XXX") {
@@ -156,4 +752,35 @@ mod tests {
             Some(_) => panic!("was expecting a None in response"),
         }
     }
+
+    #[test]
+    fn test_try_country_lookup() {
+        assert_eq!(try_lookup("DE").unwrap().code, "DEU");
+
+        let error = try_lookup("D").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Malformed);
+        let error = try_lookup("de").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Malformed);
+        assert_eq!(error.suggestions(), ["DE"]);
+        assert_eq!(
+            try_lookup("D3").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+
+        let error = try_lookup("YUG").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Withdrawn);
+        assert_eq!(error.suggestions(), ["MNE", "SRB"]);
+
+        let error = try_lookup("UK").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Reserved);
+        assert_eq!(error.suggestions(), ["GB"]);
+        assert_eq!(
+            try_lookup("XK").unwrap_err().kind(),
+            LookupErrorKind::Reserved
+        );
+
+        let error = try_lookup("DEX").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert!(error.suggestions().contains(&"DEU".to_string()));
+    }
 }
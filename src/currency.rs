@@ -18,6 +18,10 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::country::{self, CountryInfo};
+use crate::display_name;
+use crate::error::{check_alphabetic, LookupError, LookupErrorKind};
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -50,6 +54,12 @@ pub struct CurrencyInfo {
     /// These correspond approximately to _countries using
     ///this currency_.
     pub standards_entities: Vec<String>,
+    /// The ISO-3166 3-character identifiers of the countries using this
+    /// currency, resolved from `standards_entities` when the data is loaded.
+    /// Entities that are not countries, such as "European Union", are not
+    /// included.
+    #[serde(default)]
+    pub country_codes: Vec<String>,
     /// The, possibly empty set of subdivisions for this currency.
     pub subdivisions: Vec<Subdivision>,
 }
@@ -65,6 +75,8 @@ lazy_static! {
 
 /// Lookup a `CurrencyInfo` based on it's ISO-4217 3-character identifier,
 /// returning `None` if the name does not exist in the current ISO data set.
+/// This will panic if the code is not 3 characters long; see
+/// `try_lookup_by_alpha`.
 pub fn lookup_by_alpha(alphabetic_code: &str) -> Option<&'static CurrencyInfo> {
     assert_eq!(
         alphabetic_code.len(),
@@ -74,6 +86,37 @@ pub fn lookup_by_alpha(alphabetic_code: &str) -> Option<&'static CurrencyInfo> {
     CURRENCIES.get(alphabetic_code)
 }
 
+/// Lookup a `CurrencyInfo` based on it's ISO-4217 3-character identifier,
+/// returning a `LookupError` rather than panicking if the code is
+/// malformed. Withdrawn currencies, such as `DEM` for the Deutsche Mark, are
+/// reported with the currency that replaced them.
+pub fn try_lookup_by_alpha(alphabetic_code: &str) -> Result<&'static CurrencyInfo, LookupError> {
+    debug!("currency::try_lookup_by_alpha: {}", alphabetic_code);
+    check_alphabetic(
+        alphabetic_code,
+        &[3],
+        |code| code.to_ascii_uppercase(),
+        |code| CURRENCIES.contains_key(code),
+    )?;
+    if let Some(currency) = CURRENCIES.get(alphabetic_code) {
+        return Ok(currency);
+    }
+    match WITHDRAWN_CODES
+        .iter()
+        .find(|(withdrawn, _)| *withdrawn == alphabetic_code)
+    {
+        Some((_, replacement)) => Err(LookupError::new(
+            LookupErrorKind::Withdrawn,
+            alphabetic_code,
+            vec![replacement.to_string()],
+        )),
+        None => Err(LookupError::unknown(
+            alphabetic_code,
+            CURRENCIES.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
 /// Lookup a `CurrencyInfo` based on it's ISO-4217 numeric identifier,
 /// returning `None` if the name does not exist in the current ISO data set.
 pub fn lookup_by_numeric(numeric_code: &u16) -> Option<&'static CurrencyInfo> {
@@ -84,12 +127,42 @@ pub fn lookup_by_numeric(numeric_code: &u16) -> Option<&'static CurrencyInfo> {
 }
 
 /// Lookup all `CurrencyInfo` instances that are used by the identified
-/// country name.
+/// country name. The name is resolved using `country::lookup_by_name`, if
+/// it is not a known country name it is compared to the `standards_entities`
+/// of each currency instead.
 pub fn currencies_for_country_name(name: &str) -> Vec<&'static CurrencyInfo> {
-    CURRENCIES
+    match country::lookup_by_name(name) {
+        Some((country, _)) => currencies_for_country(country),
+        None => CURRENCIES
+            .values()
+            .filter(|currency| currency.standards_entities.contains(&name.to_string()))
+            .collect(),
+    }
+}
+
+/// Lookup all `CurrencyInfo` instances that are used by the provided
+/// country, sorted by alphabetic code.
+pub fn currencies_for_country(country: &CountryInfo) -> Vec<&'static CurrencyInfo> {
+    let mut currencies: Vec<&'static CurrencyInfo> = CURRENCIES
         .values()
-        .filter(|currency| currency.standards_entities.contains(&name.to_string()))
-        .collect()
+        .filter(|currency| currency.country_codes.contains(&country.code))
+        .collect();
+    currencies.sort_by(|lhs, rhs| lhs.alphabetic_code.cmp(&rhs.alphabetic_code));
+    currencies
+}
+
+/// Return the name of the identified currency, in the language of the
+/// provided locale identifier, for example `欧元` for `EUR` in `zh`; see
+/// [`display_name`](../display_name/index.html). Returns `None` if the code
+/// is not a registered currency or names are not available in the language.
+pub fn display_name(alphabetic_code: &str, in_language: &str) -> Option<&'static str> {
+    if alphabetic_code.len() != 3 {
+        return None;
+    }
+    match lookup_by_alpha(alphabetic_code) {
+        Some(currency) => display_name::currency_name(&currency.alphabetic_code, in_language),
+        None => None,
+    }
 }
 
 /// Return all the registered ISO-4217 3-character currency codes.
@@ -102,6 +175,47 @@ pub fn all_numeric_codes() -> Vec<u16> {
     NUMERIC_LOOKUP.keys().cloned().collect()
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// Codes withdrawn from ISO-4217, and the currencies that replaced them.
+const WITHDRAWN_CODES: &[(&str, &str)] = &[
+    ("ATS", "EUR"),
+    ("AZM", "AZN"),
+    ("BEF", "EUR"),
+    ("BYR", "BYN"),
+    ("CSD", "RSD"),
+    ("CYP", "EUR"),
+    ("DEM", "EUR"),
+    ("EEK", "EUR"),
+    ("ESP", "EUR"),
+    ("FIM", "EUR"),
+    ("FRF", "EUR"),
+    ("GHC", "GHS"),
+    ("GRD", "EUR"),
+    ("IEP", "EUR"),
+    ("ITL", "EUR"),
+    ("LTL", "EUR"),
+    ("LUF", "EUR"),
+    ("LVL", "EUR"),
+    ("MRO", "MRU"),
+    ("MTL", "EUR"),
+    ("MZM", "MZN"),
+    ("NLG", "EUR"),
+    ("PTE", "EUR"),
+    ("ROL", "RON"),
+    ("SDD", "SDG"),
+    ("SIT", "EUR"),
+    ("SKK", "EUR"),
+    ("STD", "STN"),
+    ("TRL", "TRY"),
+    ("VEF", "VES"),
+    ("XEU", "EUR"),
+    ("ZMK", "ZMW"),
+    ("ZWD", "ZWL"),
+];
+
 // ------------------------------------------------------------------------------------------------
 // Generated Data
 // ------------------------------------------------------------------------------------------------
@@ -109,7 +223,18 @@ pub fn all_numeric_codes() -> Vec<u16> {
 fn load_currencies_from_json() -> HashMap<String, CurrencyInfo> {
     info!("currencies_from_json - loading JSON");
     let raw_data = include_bytes!("data/currencies.json");
-    let currency_map: HashMap<String, CurrencyInfo> = serde_json::from_slice(raw_data).unwrap();
+    let mut currency_map: HashMap<String, CurrencyInfo> = serde_json::from_slice(raw_data).unwrap();
+    for currency in currency_map.values_mut() {
+        let mut country_codes: Vec<String> = currency
+            .standards_entities
+            .iter()
+            .filter_map(|entity| country::lookup_by_name(entity))
+            .map(|(country, _)| country.code.to_string())
+            .collect();
+        country_codes.sort();
+        country_codes.dedup();
+        currency.country_codes = country_codes;
+    }
     info!(
         "currencies_from_json - loaded {} currencies",
         currency_map.len()
@@ -181,4 +306,54 @@ mod tests {
         let currencies = currencies_for_country_name("Mexico");
         assert_eq!(currencies.len(), 2);
     }
+
+    #[test]
+    fn test_for_country_by_code() {
+        let holy_see = country::lookup("VAT").unwrap();
+        let currencies = currencies_for_country(holy_see);
+        assert_eq!(currencies.len(), 1);
+        assert_eq!(currencies[0].alphabetic_code, "EUR");
+
+        let bolivia = country::lookup("BOL").unwrap();
+        let codes: Vec<&str> = currencies_for_country(bolivia)
+            .iter()
+            .map(|c| c.alphabetic_code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["BOB", "BOV"]);
+    }
+
+    #[test]
+    fn test_for_country_name_variants() {
+        assert_eq!(currencies_for_country_name("Holy See").len(), 1);
+        assert_eq!(currencies_for_country_name("European Union").len(), 1);
+    }
+
+    #[test]
+    fn test_currency_display_name() {
+        assert_eq!(display_name("EUR", "zh"), Some("欧元"));
+        assert_eq!(display_name("EUR", "de"), Some("Euro"));
+        assert_eq!(display_name("JPY", "ja"), Some("日本円"));
+        assert_eq!(display_name("EURO", "de"), None);
+    }
+
+    #[test]
+    fn test_try_currency_lookup() {
+        assert_eq!(try_lookup_by_alpha("EUR").unwrap().name, "Euro");
+        assert_eq!(
+            try_lookup_by_alpha("EU").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup_by_alpha("eur").unwrap_err().suggestions(),
+            ["EUR"]
+        );
+
+        let error = try_lookup_by_alpha("DEM").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Withdrawn);
+        assert_eq!(error.suggestions(), ["EUR"]);
+
+        let error = try_lookup_by_alpha("EUX").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert!(error.suggestions().contains(&"EUR".to_string()));
+    }
 }
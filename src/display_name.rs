@@ -0,0 +1,242 @@
+/*!
+Localized display names for countries, languages, scripts, and currencies.
+
+The names held in each module's `*Info` structures are in English; this
+module provides translations of them, used by the `display_name` function in
+the `country`, `language`, `script`, and `currency` modules. The language to
+translate into is identified by a locale identifier such as `fr`, `pt-PT`, or
+`zh_Hant`; where a specific script or region is not available the base
+language is used. Where no translation exists for a name, the English name
+is returned.
+
+Country, language, and script names are also available in the short and
+variant forms CLDR defines for some of them, see `NameForm`.
+
+```rust
+use locale_codes::country;
+
+assert_eq!(country::display_name("DE", "fr"), Some("Allemagne"));
+assert_eq!(country::display_name("DEU", "de-AT"), Some("Deutschland"));
+assert_eq!(country::display_name("DE", "tlh"), None);
+```
+
+## Source
+
+The translations used here are taken from the `localeDisplayNames` of the
+Unicode [CLDR](https://cldr.unicode.org/) (version 43), for the following
+languages: Arabic, Chinese (Simplified and Traditional), Dutch, English,
+French, German, Italian, Japanese, Korean, Polish, Portuguese (Brazil and
+Portugal), Russian, Spanish, Swedish, Turkish, and Ukrainian. The English
+names of languages, scripts, and currencies not in CLDR are taken from the
+Debian [iso-codes](https://salsa.debian.org/iso-codes-team/iso-codes)
+project.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The form of a display name, as defined by CLDR. Where a name has no
+/// short or variant form the standard form is used.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum NameForm {
+    /// The name in general use; for example `United Kingdom`.
+    Standard,
+    /// A shorter form of the name, for example `UK`.
+    Short,
+    /// An alternative name in common use, for example `Ivory Coast` rather
+    /// than `Côte d’Ivoire`.
+    Variant,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref DISPLAY_NAMES: DisplayNames = load_display_names_from_json();
+}
+
+/// Return the locale identifiers names are available in, including `en`.
+pub fn supported_languages() -> Vec<String> {
+    DISPLAY_NAMES.locales.clone()
+}
+
+pub(crate) fn country_name(code: &str, in_language: &str, form: NameForm) -> Option<&'static str> {
+    localized_form(&DISPLAY_NAMES.countries, code, in_language, form)
+}
+
+pub(crate) fn language_name(code: &str, in_language: &str, form: NameForm) -> Option<&'static str> {
+    localized_form(&DISPLAY_NAMES.languages, code, in_language, form)
+}
+
+pub(crate) fn script_name(code: &str, in_language: &str, form: NameForm) -> Option<&'static str> {
+    localized_form(&DISPLAY_NAMES.scripts, code, in_language, form)
+}
+
+pub(crate) fn currency_name(code: &str, in_language: &str) -> Option<&'static str> {
+    let names = DISPLAY_NAMES.currencies.get(code)?;
+    let locale = resolve_locale(in_language)?;
+    Some(names.get(locale).unwrap_or(&names[ENGLISH]))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const ENGLISH: &str = "en";
+
+const TRADITIONAL_CHINESE_REGIONS: [&str; 3] = ["TW", "HK", "MO"];
+
+#[derive(Deserialize, Debug)]
+struct Names {
+    standard: String,
+    short: Option<String>,
+    variant: Option<String>,
+}
+
+type LocalizedNames = HashMap<String, HashMap<String, Names>>;
+
+#[derive(Deserialize, Debug)]
+struct DisplayNames {
+    locales: Vec<String>,
+    countries: LocalizedNames,
+    languages: LocalizedNames,
+    scripts: LocalizedNames,
+    currencies: HashMap<String, HashMap<String, String>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn localized_form(
+    names: &'static LocalizedNames,
+    code: &str,
+    in_language: &str,
+    form: NameForm,
+) -> Option<&'static str> {
+    let names = names.get(code)?;
+    let locale = resolve_locale(in_language)?;
+    let localized = names.get(locale).unwrap_or(&names[ENGLISH]);
+    let name = match form {
+        NameForm::Standard => None,
+        NameForm::Short => localized.short.as_ref(),
+        NameForm::Variant => localized.variant.as_ref(),
+    };
+    Some(name.unwrap_or(&localized.standard))
+}
+
+fn resolve_locale(in_language: &str) -> Option<&'static str> {
+    let parts: Vec<&str> = in_language.split(|c| c == '-' || c == '_').collect();
+    let language = parts[0].to_lowercase();
+    let script = parts[1..]
+        .iter()
+        .find(|part| part.len() == 4 && part.is_ascii())
+        .map(|part| {
+            let (first, rest) = part.split_at(1);
+            format!("{}{}", first.to_uppercase(), rest.to_lowercase())
+        });
+    let region = parts[1..]
+        .iter()
+        .find(|part| part.len() == 2)
+        .map(|part| part.to_uppercase());
+
+    let mut candidates: Vec<String> = Vec::new();
+    if let Some(script) = &script {
+        candidates.push(format!("{}_{}", language, script));
+    }
+    if let Some(region) = &region {
+        candidates.push(format!("{}_{}", language, region));
+        if language == "zh"
+            && script.is_none()
+            && TRADITIONAL_CHINESE_REGIONS.contains(&region.as_str())
+        {
+            candidates.push("zh_Hant".to_string());
+        }
+    }
+    candidates.push(language);
+    candidates.iter().find_map(|candidate| {
+        DISPLAY_NAMES
+            .locales
+            .iter()
+            .find(|locale| *locale == candidate)
+            .map(|locale| locale.as_str())
+    })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_display_names_from_json() -> DisplayNames {
+    info!("load_display_names_from_json - loading JSON");
+    let raw_data = include_bytes!("data/display_names.json");
+    let display_names: DisplayNames = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_display_names_from_json - loaded names in {} languages",
+        display_names.locales.len()
+    );
+    display_names
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_supported_languages() {
+        let languages = supported_languages();
+        assert!(languages.contains(&"en".to_string()));
+        assert!(languages.contains(&"pt_PT".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_locale() {
+        assert_eq!(resolve_locale("fr"), Some("fr"));
+        assert_eq!(resolve_locale("FR-ca"), Some("fr"));
+        assert_eq!(resolve_locale("pt-BR"), Some("pt"));
+        assert_eq!(resolve_locale("pt_PT"), Some("pt_PT"));
+        assert_eq!(resolve_locale("zh"), Some("zh"));
+        assert_eq!(resolve_locale("zh-hant"), Some("zh_Hant"));
+        assert_eq!(resolve_locale("zh-HK"), Some("zh_Hant"));
+        assert_eq!(resolve_locale("zh-Hans-HK"), Some("zh"));
+        assert_eq!(resolve_locale("en-GB"), Some("en"));
+        assert_eq!(resolve_locale("tlh"), None);
+        assert_eq!(resolve_locale(""), None);
+    }
+
+    #[test]
+    fn test_name_forms() {
+        assert_eq!(
+            country_name("GBR", "en", NameForm::Standard),
+            Some("United Kingdom")
+        );
+        assert_eq!(country_name("GBR", "en", NameForm::Short), Some("UK"));
+        assert_eq!(
+            country_name("CIV", "en", NameForm::Variant),
+            Some("Ivory Coast")
+        );
+        assert_eq!(country_name("DEU", "en", NameForm::Short), Some("Germany"));
+        assert_eq!(
+            country_name("GBR", "de", NameForm::Standard),
+            Some("Vereinigtes Königreich")
+        );
+        assert_eq!(language_name("aze", "en", NameForm::Short), Some("Azeri"));
+        assert_eq!(
+            script_name("Arab", "en", NameForm::Variant),
+            Some("Perso-Arabic")
+        );
+        assert_eq!(country_name("XXX", "en", NameForm::Standard), None);
+    }
+}
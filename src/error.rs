@@ -0,0 +1,228 @@
+/*!
+The error returned by the non-panicking `try_lookup` functions.
+
+The `lookup` functions in each module return `None` for any code they do not
+recognize, and some will panic if the code is not of the expected length.
+The `try_lookup` functions never panic and instead return a `LookupError`
+that describes why the code could not be found, along with any codes the
+caller may have meant.
+
+```rust
+use locale_codes::country;
+use locale_codes::error::LookupErrorKind;
+
+let error = country::try_lookup("D").unwrap_err();
+assert_eq!(error.kind(), LookupErrorKind::Malformed);
+
+let error = country::try_lookup("YU").unwrap_err();
+assert_eq!(error.kind(), LookupErrorKind::Withdrawn);
+assert!(error.suggestions().contains(&"RS".to_string()));
+```
+*/
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// Denotes why a code could not be found.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LookupErrorKind {
+    /// The code is not well-formed, for example it is the wrong length,
+    /// contains unexpected characters, or is in the wrong case.
+    Malformed,
+    /// The code is well-formed, but is not registered.
+    Unknown,
+    /// The code was registered, but has been withdrawn or deprecated.
+    Withdrawn,
+    /// The code is reserved, and not assigned for general use.
+    Reserved,
+}
+
+/// The error returned by the `try_lookup` functions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LookupError {
+    kind: LookupErrorKind,
+    code: String,
+    suggestions: Vec<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl LookupError {
+    /// Return the reason the code could not be found.
+    pub fn kind(&self) -> LookupErrorKind {
+        self.kind
+    }
+
+    /// Return the code that could not be found.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Return the codes the caller may have meant, the most likely first.
+    /// For a withdrawn code these are it's replacements, otherwise they
+    /// are similar registered codes.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    pub(crate) fn new(kind: LookupErrorKind, code: &str, suggestions: Vec<String>) -> Self {
+        LookupError {
+            kind,
+            code: code.to_string(),
+            suggestions,
+        }
+    }
+
+    pub(crate) fn unknown<'a, I>(code: &str, candidates: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        LookupError::new(
+            LookupErrorKind::Unknown,
+            code,
+            similar_codes(code, candidates),
+        )
+    }
+}
+
+impl Display for LookupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            LookupErrorKind::Malformed => write!(f, "'{}' is not a well-formed code", self.code)?,
+            LookupErrorKind::Unknown => write!(f, "'{}' is not a known code", self.code)?,
+            LookupErrorKind::Withdrawn => write!(f, "'{}' has been withdrawn", self.code)?,
+            LookupErrorKind::Reserved => write!(f, "'{}' is reserved", self.code)?,
+        }
+        if !self.suggestions.is_empty() {
+            let suggestions: Vec<String> = self
+                .suggestions
+                .iter()
+                .map(|suggestion| format!("'{}'", suggestion))
+                .collect();
+            write!(f, ", did you mean {}?", suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for LookupError {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const MAX_SUGGESTIONS: usize = 5;
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+/// Check that the code is made up of ASCII letters, has one of the provided
+/// lengths, and is in the case returned by `normalize`. Where only the case
+/// is wrong the normalized code is suggested, if it `exists`.
+pub(crate) fn check_alphabetic<N, E>(
+    code: &str,
+    lengths: &[usize],
+    normalize: N,
+    exists: E,
+) -> Result<(), LookupError>
+where
+    N: Fn(&str) -> String,
+    E: Fn(&str) -> bool,
+{
+    if !lengths.contains(&code.len()) || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            code,
+            Vec::new(),
+        ));
+    }
+    let normalized = normalize(code);
+    if normalized != code {
+        let suggestions = if exists(&normalized) {
+            vec![normalized]
+        } else {
+            Vec::new()
+        };
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            code,
+            suggestions,
+        ));
+    }
+    Ok(())
+}
+
+/// Return the candidates that are similar to the code, ignoring case, the
+/// most similar first.
+pub(crate) fn similar_codes<'a, I>(code: &str, candidates: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let code = code.to_lowercase();
+    let max_distance = if code.chars().count() > 4 { 2 } else { 1 };
+    let mut similar: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&code, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    similar.sort();
+    similar.dedup();
+    similar
+        .iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+pub(crate) fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    let mut previous: Vec<usize> = (0..=rhs.len()).collect();
+    for (i, lhs_char) in lhs.chars().enumerate() {
+        let mut current: Vec<usize> = vec![i + 1];
+        for (j, rhs_char) in rhs.iter().enumerate() {
+            let substitution = previous[j] + if lhs_char == *rhs_char { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[rhs.len()]
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_similar_codes() {
+        let candidates = vec!["DE", "DK", "FR", "GB"];
+        assert_eq!(similar_codes("DX", candidates.clone()), vec!["DE", "DK"]);
+        assert_eq!(similar_codes("fr", candidates.clone()), vec!["FR"]);
+        assert!(similar_codes("QQ", candidates).is_empty());
+        assert_eq!(edit_distance("caribean", "caribbean"), 1);
+    }
+
+    #[test]
+    fn test_error_display() {
+        let error = LookupError::unknown("DX", vec!["DE", "DK"]);
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert_eq!(error.code(), "DX");
+        assert_eq!(
+            error.to_string(),
+            "'DX' is not a known code, did you mean 'DE', 'DK'?"
+        );
+        let error = LookupError::new(LookupErrorKind::Malformed, "D", Vec::new());
+        assert_eq!(error.to_string(), "'D' is not a well-formed code");
+    }
+}
@@ -0,0 +1,250 @@
+/*!
+Codes for the representation of names of countries and their subdivisions
+– Part 3: Code for formerly used names of countries.
+
+ISO 3166-3 defines codes for country names which have been deleted from
+ISO 3166-1 since its first publication in 1974. Each former country name is
+assigned a 4-character code; the first two characters are the former ISO
+3166-1 2-character code, and the last two are usually the 2-character code
+of the successor country, or `HH` where there is no single successor. For
+example the USSR, formerly `SU`/`SUN`, is now identified as `SUHH`.
+
+Withdrawn codes are not returned by [`country::lookup`](../country/fn.lookup.html),
+archival data that still contains them can be resolved here, along with the
+current countries that have succeeded them.
+
+## Source - ISO 3166-3
+
+The data used here is taken from the Debian
+[iso-codes](https://salsa.debian.org/iso-codes-team/iso-codes) project,
+successor countries were compiled from the comments in the standard.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::{self, CountryInfo};
+use crate::error::{check_alphabetic, LookupError};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A representation of a withdrawn country code maintained by ISO.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FormerCountryInfo {
+    /// The ISO-3166, part 3, 4-character identifier of the former country.
+    /// This is the primary identifier.
+    pub code: String,
+    /// The withdrawn ISO-3166, part 1, 2-character identifier.
+    pub short_code: String,
+    /// The withdrawn ISO-3166, part 1, 3-character identifier.
+    pub long_code: String,
+    /// The withdrawn numeric identifier, if one was assigned.
+    pub numeric_code: Option<u16>,
+    /// The name, in English, of the former country.
+    pub name: String,
+    /// The date the code was withdrawn, either a year (`1977`) or a
+    /// complete date (`1992-08-30`).
+    pub withdrawal_date: String,
+    /// The ISO-3166, part 1, 3-character identifiers of the countries that
+    /// now cover the territory of the former country.
+    pub successor_codes: Vec<String>,
+    /// Any additional note published with the withdrawal.
+    pub comment: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref FORMER_COUNTRIES: HashMap<String, FormerCountryInfo> =
+        load_former_countries_from_json();
+    static ref LOOKUP: HashMap<String, String> = make_former_country_lookup();
+}
+
+/// Lookup a `FormerCountryInfo` based on it's ISO-3166-3 4-character
+/// identifier, or the withdrawn ISO-3166-1 2-character or 3-character
+/// identifier, returning `None` if the code was never withdrawn.
+///
+/// Withdrawn 2-character and 3-character codes that have since been
+/// reassigned to a current country, such as `SK` (formerly Sikkim, now
+/// Slovakia), are not recognized, use the 4-character identifier instead.
+///
+/// Note that the 2-character code `CS` was withdrawn twice, for
+/// Czechoslovakia and later for Serbia and Montenegro, in this case the
+/// most recent withdrawal is returned.
+pub fn lookup(code: &str) -> Option<&'static FormerCountryInfo> {
+    debug!("former_country::lookup: {}", code);
+    match code.len() {
+        4 => FORMER_COUNTRIES.get(code),
+        2 | 3 => match LOOKUP.get(code) {
+            Some(v) => lookup(v),
+            None => None,
+        },
+        _ => None,
+    }
+}
+
+/// Lookup a `FormerCountryInfo` based on it's ISO-3166-3 4-character
+/// identifier, or the withdrawn ISO-3166-1 identifier, returning a
+/// `LookupError` rather than `None` if the code is malformed or was never
+/// withdrawn.
+pub fn try_lookup(code: &str) -> Result<&'static FormerCountryInfo, LookupError> {
+    debug!("former_country::try_lookup: {}", code);
+    check_alphabetic(
+        code,
+        &[2, 3, 4],
+        |code| code.to_ascii_uppercase(),
+        |code| lookup(code).is_some(),
+    )?;
+    match lookup(code) {
+        Some(former) => Ok(former),
+        None if code.len() == 4 => Err(LookupError::unknown(
+            code,
+            FORMER_COUNTRIES.keys().map(|k| k.as_str()),
+        )),
+        None => Err(LookupError::unknown(
+            code,
+            LOOKUP
+                .keys()
+                .map(|k| k.as_str())
+                .filter(|k| k.len() == code.len()),
+        )),
+    }
+}
+
+/// Return the current countries that have succeeded the identified former
+/// country, this will be empty if the code was never withdrawn.
+pub fn successors(code: &str) -> Vec<&'static CountryInfo> {
+    match lookup(code) {
+        Some(former) => former
+            .successor_codes
+            .iter()
+            .filter_map(|code| country::lookup(code))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Return all the ISO-3166-3 4-character former country codes.
+pub fn all_codes() -> Vec<String> {
+    FORMER_COUNTRIES.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_former_countries_from_json() -> HashMap<String, FormerCountryInfo> {
+    info!("load_former_countries_from_json - loading JSON");
+    let raw_data = include_bytes!("data/former_countries.json");
+    let former_map: HashMap<String, FormerCountryInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_former_countries_from_json - loaded {} former countries",
+        former_map.len()
+    );
+    former_map
+}
+
+fn make_former_country_lookup() -> HashMap<String, String> {
+    info!("make_former_country_lookup - create from FORMER_COUNTRIES");
+    let mut lookup_map: HashMap<String, String> = HashMap::new();
+    let mut by_date: Vec<&FormerCountryInfo> = FORMER_COUNTRIES.values().collect();
+    by_date.sort_by(|lhs, rhs| lhs.withdrawal_date.cmp(&rhs.withdrawal_date));
+    for former in by_date {
+        for code in &[&former.short_code, &former.long_code] {
+            if country::lookup(code).is_none() {
+                lookup_map.insert(code.to_string(), former.code.to_string());
+            }
+        }
+    }
+    info!(
+        "make_former_country_lookup - mapped {} codes",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::LookupErrorKind;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_former_country_codes() {
+        let codes = all_codes();
+        assert!(!codes.is_empty());
+    }
+
+    #[test]
+    fn test_good_former_country_code() {
+        for code in &["SUHH", "SUN", "SU"] {
+            match lookup(code) {
+                None => panic!("was expecting a former country"),
+                Some(former) => {
+                    assert_eq!(former.code, "SUHH");
+                    assert_eq!(former.numeric_code, Some(810));
+                    assert_eq!(former.withdrawal_date, "1992-08-30");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_former_country_code() {
+        assert!(country::lookup("YUG").is_none());
+        match lookup("DEU") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_short_code() {
+        assert_eq!(lookup("CS").unwrap().code, "CSXX");
+        assert_eq!(lookup("CSK").unwrap().code, "CSHH");
+    }
+
+    #[test]
+    fn test_reassigned_code() {
+        assert_eq!(lookup("SKIN").unwrap().name, "Sikkim");
+        assert!(lookup("SK").is_none());
+        assert_eq!(lookup("SKM").unwrap().code, "SKIN");
+        assert_eq!(country::lookup("SK").unwrap().code, "SVK");
+    }
+
+    #[test]
+    fn test_successors() {
+        let codes: Vec<&str> = successors("ANT")
+            .iter()
+            .map(|country| country.code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["BES", "CUW", "SXM"]);
+        assert!(successors("DEU").is_empty());
+    }
+
+    #[test]
+    fn test_try_former_country_lookup() {
+        assert_eq!(try_lookup("YU").unwrap().code, "YUCS");
+        assert_eq!(
+            try_lookup("yu").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("YUCSX").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        let error = try_lookup("DE").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert!(error.suggestions().contains(&"DD".to_string()));
+    }
+}
@@ -0,0 +1,298 @@
+/*!
+Membership of countries in international groups and economic unions.
+
+Many rules depend on whether a country is a member of some group; for
+example the EU for VAT, SEPA for payments, or the Schengen Area for travel.
+Membership of these groups changes over time, so each membership records the
+date it took effect and, if it has ended, the date it ended. All dates are in
+ISO 8601 `YYYY-MM-DD` format.
+
+The following groups are included: `ASEAN`, `BRICS`, `EEA`, `EFTA`, `EU`,
+`EUROZONE`, `G20`, `G7`, `GCC`, `MERCOSUR`, `OECD`, `SCHENGEN`, and `SEPA`.
+
+```rust
+use locale_codes::group;
+
+assert!(!group::is_member("HRV", "EUROZONE", "2022-12-31"));
+assert!(group::is_member("HRV", "EUROZONE", "2023-01-01"));
+```
+
+## Source
+
+The data used here was compiled from the membership pages published by each
+organization.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::{self, CountryInfo};
+use crate::error::{LookupError, LookupErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A single period of membership of a country in a group.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Membership {
+    /// The ISO-3166, part 1, 3-character identifier of the member country.
+    pub country_code: String,
+    /// The date membership took effect.
+    pub joined: String,
+    /// The first date on which the country was no longer a member, if the
+    /// membership has ended or been suspended.
+    pub left: Option<String>,
+    /// Any additional note on the membership.
+    pub comment: Option<String>,
+}
+
+/// A representation of an international group of countries.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GroupInfo {
+    /// The identifier of the group, for example `EUROZONE`.
+    pub code: String,
+    /// The name of the group, in English.
+    pub name: String,
+    /// The date the group was established.
+    pub established: String,
+    /// All past, and present, memberships of the group; a country may have
+    /// more than one membership if it has left and rejoined.
+    pub members: Vec<Membership>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref GROUPS: HashMap<String, GroupInfo> = load_groups_from_json();
+}
+
+/// Lookup a `GroupInfo` based on it's identifier, returning `None` if the
+/// group is unknown.
+pub fn lookup(code: &str) -> Option<&'static GroupInfo> {
+    debug!("group::lookup: {}", code);
+    GROUPS.get(code)
+}
+
+/// Lookup a `GroupInfo` based on it's identifier, returning a `LookupError`
+/// rather than `None` if the identifier is empty or unknown.
+pub fn try_lookup(code: &str) -> Result<&'static GroupInfo, LookupError> {
+    debug!("group::try_lookup: {}", code);
+    if code.trim().is_empty() {
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            code,
+            Vec::new(),
+        ));
+    }
+    match lookup(code) {
+        Some(info) => Ok(info),
+        None => Err(LookupError::unknown(
+            code,
+            GROUPS.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
+/// Returns `true` if the country, identified by it's ISO-3166 2-character or
+/// 3-character code, was a member of the group on the provided date. The
+/// date must be in the form `YYYY-MM-DD`, `false` is returned for any other
+/// form, or if the country or group code is malformed or unknown.
+pub fn is_member(country_code: &str, group_code: &str, date: &str) -> bool {
+    match (country::try_lookup(country_code).ok(), lookup(group_code)) {
+        (Some(country), Some(group)) => group.is_member(country, date),
+        _ => false,
+    }
+}
+
+/// Return the countries that were members of the group on the provided
+/// date, sorted by code. This is empty if the date is not in the form
+/// `YYYY-MM-DD`.
+pub fn members_on(group_code: &str, date: &str) -> Vec<&'static CountryInfo> {
+    match lookup(group_code) {
+        Some(group) => {
+            let mut members: Vec<&'static CountryInfo> = group
+                .members
+                .iter()
+                .filter(|membership| membership.is_current_on(date))
+                .filter_map(|membership| country::lookup(&membership.country_code))
+                .collect();
+            members.sort_by(|lhs, rhs| lhs.code.cmp(&rhs.code));
+            members.dedup_by(|lhs, rhs| lhs.code == rhs.code);
+            members
+        }
+        None => Vec::new(),
+    }
+}
+
+/// Return the groups the provided country was a member of on the provided
+/// date, sorted by code. This is empty if the date is not in the form
+/// `YYYY-MM-DD`.
+pub fn groups_for(country: &CountryInfo, date: &str) -> Vec<&'static GroupInfo> {
+    let mut groups: Vec<&'static GroupInfo> = GROUPS
+        .values()
+        .filter(|group| group.is_member(country, date))
+        .collect();
+    groups.sort_by(|lhs, rhs| lhs.code.cmp(&rhs.code));
+    groups
+}
+
+/// Return all the group identifiers.
+pub fn all_codes() -> Vec<String> {
+    GROUPS.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl GroupInfo {
+    /// Returns `true` if the provided country was a member of this group on
+    /// the provided date.
+    pub fn is_member(&self, country: &CountryInfo, date: &str) -> bool {
+        self.members.iter().any(|membership| {
+            membership.country_code == country.code && membership.is_current_on(date)
+        })
+    }
+}
+
+impl Membership {
+    /// Returns `true` if this membership was in effect on the provided date,
+    /// the date must be in the form `YYYY-MM-DD`.
+    pub fn is_current_on(&self, date: &str) -> bool {
+        is_date(date)
+            && self.joined.as_str() <= date
+            && match &self.left {
+                Some(left) => date < left.as_str(),
+                None => true,
+            }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_date(date: &str) -> bool {
+    date.len() == 10
+        && date.bytes().enumerate().all(|(i, c)| match i {
+            4 | 7 => c == b'-',
+            _ => c.is_ascii_digit(),
+        })
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_groups_from_json() -> HashMap<String, GroupInfo> {
+    info!("load_groups_from_json - loading JSON");
+    let raw_data = include_bytes!("data/groups.json");
+    let group_map: HashMap<String, GroupInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!("load_groups_from_json - loaded {} groups", group_map.len());
+    group_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_group_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"EUROZONE".to_string()));
+    }
+
+    #[test]
+    fn test_good_group_code() {
+        match lookup("G7") {
+            None => panic!("was expecting a group"),
+            Some(group) => {
+                assert_eq!(group.name, "Group of Seven");
+                assert_eq!(group.members.len(), 7);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_group_code() {
+        match lookup("G99") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+        assert!(!is_member("FRA", "G99", "2020-01-01"));
+        assert!(!is_member("XXX", "EU", "2020-01-01"));
+    }
+
+    #[test]
+    fn test_is_member() {
+        assert!(!is_member("HRV", "EUROZONE", "2022-12-31"));
+        assert!(is_member("HRV", "EUROZONE", "2023-01-01"));
+        assert!(is_member("HR", "EU", "2013-07-01"));
+
+        assert!(is_member("GBR", "EU", "2020-01-31"));
+        assert!(!is_member("GBR", "EU", "2020-02-01"));
+        assert!(is_member("GBR", "SEPA", "2024-01-01"));
+        assert!(!is_member("DEU", "EU", "1957-12-31"));
+    }
+
+    #[test]
+    fn test_is_member_bad_country() {
+        assert!(!is_member("", "EU", "2024-01-01"));
+        assert!(!is_member("Germany", "EU", "2024-01-01"));
+        assert!(!is_member("deu", "EU", "2024-01-01"));
+        assert!(!is_member("QQQ", "EU", "2024-01-01"));
+    }
+
+    #[test]
+    fn test_members_on() {
+        let founders: Vec<&str> = members_on("EU", "1958-01-01")
+            .iter()
+            .map(|c| c.code.as_str())
+            .collect();
+        assert_eq!(founders, vec!["BEL", "DEU", "FRA", "ITA", "LUX", "NLD"]);
+        assert_eq!(members_on("EU", "2021-01-01").len(), 27);
+        assert_eq!(members_on("EU", "2019-01-01").len(), 28);
+        assert!(members_on("G99", "2019-01-01").is_empty());
+    }
+
+    #[test]
+    fn test_groups_for() {
+        let norway = country::lookup("NOR").unwrap();
+        let codes: Vec<&str> = groups_for(norway, "2024-01-01")
+            .iter()
+            .map(|g| g.code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["EEA", "EFTA", "OECD", "SCHENGEN", "SEPA"]);
+    }
+
+    #[test]
+    fn test_malformed_date() {
+        assert!(!is_member("HRV", "EUROZONE", "2023-1-1"));
+        assert!(!is_member("HRV", "EUROZONE", "2023"));
+        assert!(members_on("EU", "2021/01/01").is_empty());
+        let norway = country::lookup("NOR").unwrap();
+        assert!(groups_for(norway, "20240101").is_empty());
+    }
+
+    #[test]
+    fn test_try_group_lookup() {
+        assert_eq!(try_lookup("EU").unwrap().code, "EU");
+        assert_eq!(
+            try_lookup("").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        let error = try_lookup("eurozone").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert_eq!(error.suggestions()[0], "EUROZONE");
+        assert!(try_lookup("NATOXYZ").unwrap_err().suggestions().is_empty());
+    }
+}
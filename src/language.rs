@@ -32,6 +32,9 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::display_name::{self, NameForm};
+use crate::error::{check_alphabetic, LookupError, LookupErrorKind};
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -103,7 +106,8 @@ lazy_static! {
 
 /// Lookup a `LanguageInfo` based on it's ISO-639 2, or 3, character
 /// identifier, returning `None` if the name does not exist in the
-/// current ISO data set.
+/// current ISO data set. This will panic if the code is not 2, or 3,
+/// characters long; see `try_lookup`.
 pub fn lookup(code: &str) -> Option<&'static LanguageInfo> {
     debug!("language::lookup {}", code);
     assert!(
@@ -126,11 +130,99 @@ pub fn lookup(code: &str) -> Option<&'static LanguageInfo> {
     }
 }
 
+/// Lookup a `LanguageInfo` based on it's ISO-639 2, or 3, character
+/// identifier, returning a `LookupError` rather than panicking if the code
+/// is malformed. Deprecated codes, such as `iw` for Hebrew, are reported as
+/// withdrawn with their replacement; ISO-639-2 bibliographic codes, such as
+/// `ger`, suggest the corresponding terminology code.
+pub fn try_lookup(code: &str) -> Result<&'static LanguageInfo, LookupError> {
+    debug!("language::try_lookup {}", code);
+    check_alphabetic(
+        code,
+        &[2, 3],
+        |code| code.to_ascii_lowercase(),
+        |code| lookup(code).is_some(),
+    )?;
+    if let Some(language) = lookup(code) {
+        return Ok(language);
+    }
+    if let Some((_, replacement)) = DEPRECATED_CODES
+        .iter()
+        .find(|(deprecated, _)| *deprecated == code)
+    {
+        return Err(LookupError::new(
+            LookupErrorKind::Withdrawn,
+            code,
+            vec![replacement.to_string()],
+        ));
+    }
+    if let Some(language) = LANGUAGES
+        .values()
+        .find(|language| language.bibliographic_code.as_ref().map(String::as_str) == Some(code))
+    {
+        return Err(LookupError::new(
+            LookupErrorKind::Unknown,
+            code,
+            vec![language.code.to_string()],
+        ));
+    }
+    if code.len() == 2 {
+        Err(LookupError::unknown(
+            code,
+            LOOKUP.keys().map(|k| k.as_str()),
+        ))
+    } else {
+        Err(LookupError::unknown(
+            code,
+            LANGUAGES.keys().map(|k| k.as_str()),
+        ))
+    }
+}
+
+/// Return the name of the identified language, in the language of the
+/// provided locale identifier, for example `Deutsch` for `de` in `de`; see
+/// [`display_name`](../display_name/index.html). Returns `None` if the code
+/// is not a registered language or names are not available in the language.
+pub fn display_name(code: &str, in_language: &str) -> Option<&'static str> {
+    display_name_with_form(code, in_language, NameForm::Standard)
+}
+
+/// Return the name of the identified language, in the provided form, in the
+/// language of the provided locale identifier; for example `Azeri` is the
+/// short form for `az` in `en`.
+pub fn display_name_with_form(
+    code: &str,
+    in_language: &str,
+    form: NameForm,
+) -> Option<&'static str> {
+    if !(code.len() == 2 || code.len() == 3) {
+        return None;
+    }
+    match lookup(code) {
+        Some(language) => display_name::language_name(&language.code, in_language, form),
+        None => None,
+    }
+}
+
 /// Return all the registered ISO-639 2-character language codes.
 pub fn all_codes() -> Vec<String> {
     LANGUAGES.keys().cloned().collect()
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// Codes withdrawn from ISO-639, and their replacements.
+const DEPRECATED_CODES: &[(&str, &str)] = &[
+    ("in", "id"),
+    ("iw", "he"),
+    ("ji", "yi"),
+    ("jw", "jv"),
+    ("mo", "ro"),
+    ("mol", "ron"),
+];
+
 // ------------------------------------------------------------------------------------------------
 // Generated Data
 // ------------------------------------------------------------------------------------------------
@@ -179,4 +271,31 @@ mod tests {
             Some(l) => println!("test_language_loading {:#?}", to_string_pretty(l)),
         }
     }
+
+    #[test]
+    fn test_language_display_name() {
+        assert_eq!(display_name("de", "de"), Some("Deutsch"));
+        assert_eq!(display_name("deu", "ja"), Some("ドイツ語"));
+        assert_eq!(display_name("de", "en"), Some("German"));
+        assert_eq!(display_name("de", "tlh"), None);
+        assert_eq!(display_name("german", "de"), None);
+    }
+
+    #[test]
+    fn test_try_language_lookup() {
+        assert_eq!(try_lookup("de").unwrap().code, "deu");
+        assert_eq!(
+            try_lookup("D").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(try_lookup("DE").unwrap_err().suggestions(), ["de"]);
+
+        let error = try_lookup("iw").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Withdrawn);
+        assert_eq!(error.suggestions(), ["he"]);
+
+        let error = try_lookup("ger").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert_eq!(error.suggestions(), ["deu"]);
+    }
 }
@@ -19,6 +19,9 @@ are definitely some patterns all of the current implementations follow.
 1. Most will also include a function `all_codes()` to retrieve a vector of all
    the known identifiers,
 1. or, `all_alpha_codes()` and `all_numeric_codes()` as appropriate.
+1. Modules also implement a `try_lookup()` function (or `try_lookup_by_alpha()`)
+   that never panics, returning a [`LookupError`](error/struct.LookupError.html)
+   describing why a code was malformed, unknown, withdrawn, or reserved.
 
 Some standards, specifically language and country, support 2-character and
 3-character alphabetic identifiers, a single `lookup()` function is used to
@@ -36,9 +39,9 @@ least some reasonable use cases.
    by the ISO-3166, part 2, 3-character identifier.
 1. The data fromn the last call contains one or more regions (in the
    [`RegionInfo`](/codes/region/struct.RegionInfo.html) struct), determine
-   the countries name from the `country_code`.
-1. Now we have the country name we can lookup the details of the currencies
-   (in, the [`CurrencyInfo`](CurrencyInfo) struct).
+   the region the country is in from the `region_code`.
+1. Finally, lookup the details of the currencies used by the country (in,
+   the [`CurrencyInfo`](CurrencyInfo) struct).
 
 ```
 use locale_codes::{country, currency, region};
@@ -46,10 +49,10 @@ use locale_codes::{country, currency, region};
 let mexico = country::lookup("MEX").unwrap();
 println!("{:?}", mexico);
 
-let mexico_region = region::lookup(mexico.country_code).unwrap();
+let mexico_region = region::lookup(mexico.region_code.unwrap()).unwrap();
 println!("{:?}", mexico_region);
 
-let currencies = currency::currencies_for_country_name(mexico_region.name.as_str());
+let currencies = currency::currencies_for_country(mexico);
 println!("{:?}", currencies);
 ```
 
@@ -66,11 +69,34 @@ Currently data is generated for the following standards:
 * ISO 639 _Codes for the representation of names of languages_; Parts 1-4,
   2-character and 3-character codes supported.
 * ISO 3166 _Codes for the representation of names of countries and their
-  subdivisions_; Part 1, 2-character codes, only.
+  subdivisions_; Part 1, 2-character and 3-character codes, Part 2
+  subdivision codes, and Part 3 formerly used codes.
 * ISO 4217 _Codes for the representation of currencies_; alphabetic and
   numeric codes supported.
 * ISO 15924 _Codes for the representation of names of scripts_; alphabetic
   and numeric codes supported.
+* ITU-T E.164 _The international public telecommunication numbering plan_;
+  country calling codes.
+* IANA _Time Zone Database_; zone identifiers, aliases, and the countries
+  using them, with the CLDR mapping from Windows time zone identifiers.
+* IANA _Root Zone Database_; country code, and internationalized country
+  code, top-level domains.
+* Unicode CLDR _Territory information_; the languages used within each
+  country.
+* Unicode CLDR _Measurement data_; the measurement system and paper size
+  used within each country, and unit patterns for a few languages.
+* Country groups; membership of the EU, Euro area, Schengen Area, OECD,
+  and other international groups, with accession dates.
+* Postal code formats; patterns, examples, and normalization for each
+  country's postal codes.
+* Address formats; field order, required fields, local labels, and the
+  formatting of mailing labels for each country.
+* ISO 13616 _International Bank Account Number_; IBAN formats, and the
+  currency, for each country including Kosovo (`XK`), with BIC validation.
+* Tax identifiers; structure and check digit validation of EU VAT numbers,
+  and other national schemes such as the Australian Business Number.
+* Display names; translations of country, language, script, and currency
+  names into 17 languages.
 
 Each folder under `src-data` represents a single standard, which may
 generate one or more data sets. Each directory will contain a Python
@@ -95,14 +121,42 @@ extern crate log;
 // Public Modules
 // ------------------------------------------------------------------------------------------------
 
+pub mod address;
+
+pub mod banking;
+
+pub mod calling_code;
+
 pub mod codeset;
 
 pub mod country;
 
 pub mod currency;
 
+pub mod display_name;
+
+pub mod error;
+
+pub mod former_country;
+
+pub mod group;
+
 pub mod language;
 
+pub mod measurement;
+
+pub mod postal_code;
+
 pub mod region;
 
 pub mod script;
+
+pub mod subdivision;
+
+pub mod tax_id;
+
+pub mod territory;
+
+pub mod timezone;
+
+pub mod tld;
@@ -0,0 +1,559 @@
+/*!
+Measurement systems, paper sizes, and the formatting of measurement units.
+
+Most countries use the metric system, with A4 paper; the exceptions are
+recorded by CLDR. The United States, Liberia, and Myanmar use the US
+customary system, the United Kingdom uses a mix of the two (miles, feet, and
+inches for length, with metric units otherwise), and a few countries that are
+otherwise metric use Fahrenheit for temperatures. US Letter paper is used in
+the United States, Canada, and much of Central and South America.
+
+A quantity can be converted to the units preferred in a country, and
+formatted using the unit patterns of a language, for example:
+
+```rust
+use locale_codes::{country, language, measurement};
+use locale_codes::measurement::{Unit, UnitStyle};
+
+let usa = country::lookup("USA").unwrap();
+let english = language::lookup("en").unwrap();
+
+let text = measurement::format_measure(5.0, Unit::Kilometer, usa, english, UnitStyle::Short);
+assert_eq!(text, Some("3.1 mi".to_string()));
+```
+
+## Source - CLDR
+
+The data used here is taken from the `measurementData` section of the CLDR
+[supplemental data](https://github.com/unicode-org/cldr/blob/main/common/supplemental/supplementalData.xml),
+and the unit patterns from the CLDR
+[locale data](https://github.com/unicode-org/cldr/tree/main/common/main) for
+a small number of languages.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::CountryInfo;
+use crate::language::LanguageInfo;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The system of measurement used within a country.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MeasurementSystem {
+    /// The metric, or SI, system.
+    Metric,
+    /// The US customary system.
+    US,
+    /// The mix of metric and imperial units used in the United Kingdom.
+    UK,
+}
+
+/// The standard paper size used within a country.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaperSize {
+    /// ISO 216 A4, 210 x 297 mm.
+    A4,
+    /// US Letter, 8.5 x 11 inches.
+    UsLetter,
+}
+
+/// The kinds of quantity that may be measured.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitCategory {
+    /// Length, or distance.
+    Length,
+    /// Mass, or weight.
+    Mass,
+    /// Temperature.
+    Temperature,
+    /// Volume of a liquid.
+    Volume,
+}
+
+/// The units that may be converted and formatted.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Unit {
+    /// Length, 1,000 meters.
+    Kilometer,
+    /// Length, the SI unit.
+    Meter,
+    /// Length, 1/100 of a meter.
+    Centimeter,
+    /// Length, 5,280 feet.
+    Mile,
+    /// Length, 12 inches.
+    Foot,
+    /// Length, 25.4 millimeters.
+    Inch,
+    /// Mass, the SI unit.
+    Kilogram,
+    /// Mass, 1/1,000 of a kilogram.
+    Gram,
+    /// Mass, the avoirdupois pound.
+    Pound,
+    /// Mass, 1/16 of a pound.
+    Ounce,
+    /// Temperature, degrees Celsius.
+    Celsius,
+    /// Temperature, degrees Fahrenheit.
+    Fahrenheit,
+    /// Volume, 1 cubic decimeter.
+    Liter,
+    /// Volume, 1/1,000 of a liter.
+    Milliliter,
+    /// Volume, the US liquid gallon.
+    Gallon,
+    /// Volume, the US fluid ounce, 1/128 of a gallon.
+    FluidOunce,
+}
+
+/// The style used to format a unit.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitStyle {
+    /// The abbreviated form, for example "5 km".
+    Short,
+    /// The full form, for example "5 kilometers".
+    Long,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref MEASUREMENT_SYSTEMS: HashMap<String, MeasurementInfo> =
+        load_measurement_systems_from_json();
+    static ref UNIT_PATTERNS: HashMap<String, NumberFormat> = load_unit_patterns_from_json();
+}
+
+/// Return the measurement system used within the provided country.
+pub fn measurement_system(country: &CountryInfo) -> MeasurementSystem {
+    match MEASUREMENT_SYSTEMS.get(&country.short_code) {
+        Some(info) => info.system,
+        None => MeasurementSystem::Metric,
+    }
+}
+
+/// Return the measurement system used for temperatures within the provided
+/// country; this is either `Metric` (Celsius) or `US` (Fahrenheit).
+pub fn temperature_system(country: &CountryInfo) -> MeasurementSystem {
+    match MEASUREMENT_SYSTEMS.get(&country.short_code) {
+        Some(info) => info.temperature_system,
+        None => MeasurementSystem::Metric,
+    }
+}
+
+/// Return the standard paper size used within the provided country.
+pub fn paper_size(country: &CountryInfo) -> PaperSize {
+    match MEASUREMENT_SYSTEMS.get(&country.short_code) {
+        Some(info) => info.paper_size,
+        None => PaperSize::A4,
+    }
+}
+
+/// Return the units preferred for the category of quantity within the
+/// provided country, ordered from the largest to the smallest.
+pub fn preferred_units(category: UnitCategory, country: &CountryInfo) -> Vec<Unit> {
+    let system = match category {
+        UnitCategory::Temperature => temperature_system(country),
+        _ => measurement_system(country),
+    };
+    match (category, system) {
+        (UnitCategory::Length, MeasurementSystem::Metric) => {
+            vec![Unit::Kilometer, Unit::Meter, Unit::Centimeter]
+        }
+        (UnitCategory::Length, _) => vec![Unit::Mile, Unit::Foot, Unit::Inch],
+        (UnitCategory::Mass, MeasurementSystem::US) => vec![Unit::Pound, Unit::Ounce],
+        (UnitCategory::Mass, _) => vec![Unit::Kilogram, Unit::Gram],
+        (UnitCategory::Temperature, MeasurementSystem::US) => vec![Unit::Fahrenheit],
+        (UnitCategory::Temperature, _) => vec![Unit::Celsius],
+        (UnitCategory::Volume, MeasurementSystem::US) => vec![Unit::Gallon, Unit::FluidOunce],
+        (UnitCategory::Volume, _) => vec![Unit::Liter, Unit::Milliliter],
+    }
+}
+
+/// Return the ISO 639-1 codes of the languages with unit patterns.
+pub fn supported_languages() -> Vec<String> {
+    let mut languages: Vec<String> = UNIT_PATTERNS.keys().cloned().collect();
+    languages.sort();
+    languages
+}
+
+/// Convert a value from one unit to another, returning `None` if the units
+/// measure different categories of quantity.
+pub fn convert(value: f64, from: Unit, to: Unit) -> Option<f64> {
+    if from.category() != to.category() {
+        return None;
+    }
+    let (from_factor, from_offset) = from.conversion();
+    let (to_factor, to_offset) = to.conversion();
+    Some((value * from_factor + from_offset - to_offset) / to_factor)
+}
+
+/// Format a value, converting it to the unit preferred within the provided
+/// country, using the unit patterns of the provided language. The largest
+/// preferred unit in which the value is at least 1 is used, and the value is
+/// rounded to one decimal place. This returns `None` if the language has no
+/// unit patterns, see `supported_languages`, or the value is not finite or
+/// too large to format.
+pub fn format_measure(
+    value: f64,
+    unit: Unit,
+    country: &CountryInfo,
+    language: &LanguageInfo,
+    style: UnitStyle,
+) -> Option<String> {
+    let units = preferred_units(unit.category(), country);
+    let converted = units
+        .iter()
+        .filter_map(|to| convert(value, unit, *to).map(|value| (value, *to)))
+        .find(|(value, _)| (value.abs() * 10.0).round() >= 10.0)
+        .or_else(|| {
+            units
+                .last()
+                .and_then(|to| convert(value, unit, *to).map(|value| (value, *to)))
+        });
+    match converted {
+        Some((value, unit)) => format_unit(value, unit, language, style),
+        None => format_unit(value, unit, language, style),
+    }
+}
+
+/// Format a value in the provided unit, without conversion, using the unit
+/// patterns of the provided language. The value is rounded to one decimal
+/// place. This returns `None` if the language has no unit patterns, see
+/// `supported_languages`, or the value is not finite or too large to format.
+pub fn format_unit(
+    value: f64,
+    unit: Unit,
+    language: &LanguageInfo,
+    style: UnitStyle,
+) -> Option<String> {
+    let format = UNIT_PATTERNS.get(language.short_code.as_ref()?)?;
+    // Float to integer casts of values out of range, including NaN and the
+    // infinities, are undefined before Rust 1.45.
+    let rounded = (value.abs() * 10.0).round();
+    if !value.is_finite() || rounded >= TENTHS_LIMIT {
+        return None;
+    }
+    let tenths = rounded as u64;
+    let patterns = &format.units[&unit];
+    let pattern = match style {
+        UnitStyle::Short => &patterns.short,
+        UnitStyle::Long if format.plural.is_one(tenths) => &patterns.long_one,
+        UnitStyle::Long => &patterns.long_other,
+    };
+    let sign = if value < 0.0 && tenths > 0 { "-" } else { "" };
+    Some(pattern.replace("{0}", &format!("{}{}", sign, format.format_tenths(tenths))))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Unit {
+    /// Return the category of quantity this unit measures.
+    pub fn category(&self) -> UnitCategory {
+        match self {
+            Unit::Kilometer | Unit::Meter | Unit::Centimeter => UnitCategory::Length,
+            Unit::Mile | Unit::Foot | Unit::Inch => UnitCategory::Length,
+            Unit::Kilogram | Unit::Gram | Unit::Pound | Unit::Ounce => UnitCategory::Mass,
+            Unit::Celsius | Unit::Fahrenheit => UnitCategory::Temperature,
+            Unit::Liter | Unit::Milliliter | Unit::Gallon | Unit::FluidOunce => {
+                UnitCategory::Volume
+            }
+        }
+    }
+
+    // The factor, and offset, to convert to the base unit of the category;
+    // meters, kilograms, degrees Celsius, and liters.
+    fn conversion(&self) -> (f64, f64) {
+        match self {
+            Unit::Kilometer => (1000.0, 0.0),
+            Unit::Meter => (1.0, 0.0),
+            Unit::Centimeter => (0.01, 0.0),
+            Unit::Mile => (1609.344, 0.0),
+            Unit::Foot => (0.3048, 0.0),
+            Unit::Inch => (0.0254, 0.0),
+            Unit::Kilogram => (1.0, 0.0),
+            Unit::Gram => (0.001, 0.0),
+            Unit::Pound => (0.453_592_37, 0.0),
+            Unit::Ounce => (0.028_349_523_125, 0.0),
+            Unit::Celsius => (1.0, 0.0),
+            Unit::Fahrenheit => (5.0 / 9.0, -160.0 / 9.0),
+            Unit::Liter => (1.0, 0.0),
+            Unit::Milliliter => (0.001, 0.0),
+            Unit::Gallon => (3.785_411_784, 0.0),
+            Unit::FluidOunce => (0.029_573_529_562_5, 0.0),
+        }
+    }
+}
+
+impl PluralRule {
+    fn is_one(&self, tenths: u64) -> bool {
+        match self {
+            PluralRule::One => tenths == 10,
+            PluralRule::ZeroOrOne => tenths < 20,
+        }
+    }
+}
+
+impl NumberFormat {
+    fn format_tenths(&self, tenths: u64) -> String {
+        let digits = (tenths / 10).to_string();
+        let mut integer = String::new();
+        let grouped = digits.len() >= 3 + self.minimum_grouping;
+        for (i, c) in digits.chars().enumerate() {
+            if grouped && i > 0 && i % 3 == digits.len() % 3 {
+                integer.push_str(&self.group);
+            }
+            integer.push(c);
+        }
+        match tenths % 10 {
+            0 => integer,
+            fraction => format!("{}{}{}", integer, self.decimal, fraction),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Deserialize, Debug)]
+struct MeasurementInfo {
+    system: MeasurementSystem,
+    temperature_system: MeasurementSystem,
+    paper_size: PaperSize,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+enum PluralRule {
+    One,
+    ZeroOrOne,
+}
+
+#[derive(Deserialize, Debug)]
+struct UnitPatterns {
+    short: String,
+    long_one: String,
+    long_other: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct NumberFormat {
+    decimal: String,
+    group: String,
+    minimum_grouping: usize,
+    plural: PluralRule,
+    units: HashMap<Unit, UnitPatterns>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+// 2^64, the smallest number of tenths that does not fit in a u64.
+const TENTHS_LIMIT: f64 = 18_446_744_073_709_551_616.0;
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_measurement_systems_from_json() -> HashMap<String, MeasurementInfo> {
+    info!("load_measurement_systems_from_json - loading JSON");
+    let raw_data = include_bytes!("data/measurement_systems.json");
+    let system_map: HashMap<String, MeasurementInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_measurement_systems_from_json - loaded {} territories",
+        system_map.len()
+    );
+    system_map
+}
+
+fn load_unit_patterns_from_json() -> HashMap<String, NumberFormat> {
+    info!("load_unit_patterns_from_json - loading JSON");
+    let raw_data = include_bytes!("data/unit_patterns.json");
+    let pattern_map: HashMap<String, NumberFormat> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_unit_patterns_from_json - loaded {} languages",
+        pattern_map.len()
+    );
+    pattern_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{country, language};
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_measurement_system() {
+        let usa = country::lookup("USA").unwrap();
+        assert_eq!(measurement_system(usa), MeasurementSystem::US);
+        assert_eq!(paper_size(usa), PaperSize::UsLetter);
+
+        let uk = country::lookup("GBR").unwrap();
+        assert_eq!(measurement_system(uk), MeasurementSystem::UK);
+        assert_eq!(temperature_system(uk), MeasurementSystem::Metric);
+        assert_eq!(paper_size(uk), PaperSize::A4);
+
+        let germany = country::lookup("DEU").unwrap();
+        assert_eq!(measurement_system(germany), MeasurementSystem::Metric);
+        assert_eq!(paper_size(germany), PaperSize::A4);
+
+        let canada = country::lookup("CAN").unwrap();
+        assert_eq!(measurement_system(canada), MeasurementSystem::Metric);
+        assert_eq!(paper_size(canada), PaperSize::UsLetter);
+
+        let bahamas = country::lookup("BHS").unwrap();
+        assert_eq!(measurement_system(bahamas), MeasurementSystem::Metric);
+        assert_eq!(temperature_system(bahamas), MeasurementSystem::US);
+    }
+
+    #[test]
+    fn test_convert() {
+        assert!((convert(100.0, Unit::Celsius, Unit::Fahrenheit).unwrap() - 212.0).abs() < 1e-9);
+        assert!((convert(1.0, Unit::Mile, Unit::Kilometer).unwrap() - 1.609344).abs() < 1e-9);
+        assert!(convert(1.0, Unit::Mile, Unit::Kilogram).is_none());
+    }
+
+    #[test]
+    fn test_preferred_units() {
+        let uk = country::lookup("GBR").unwrap();
+        assert_eq!(preferred_units(UnitCategory::Length, uk)[0], Unit::Mile);
+        assert_eq!(preferred_units(UnitCategory::Mass, uk)[0], Unit::Kilogram);
+        assert_eq!(
+            preferred_units(UnitCategory::Temperature, uk),
+            vec![Unit::Celsius]
+        );
+    }
+
+    #[test]
+    fn test_format_measure() {
+        let usa = country::lookup("USA").unwrap();
+        let france = country::lookup("FRA").unwrap();
+        let english = language::lookup("en").unwrap();
+        let french = language::lookup("fr").unwrap();
+        let german = language::lookup("de").unwrap();
+
+        assert_eq!(
+            format_measure(5.0, Unit::Kilometer, usa, english, UnitStyle::Short),
+            Some("3.1 mi".to_string())
+        );
+        assert_eq!(
+            format_measure(20.0, Unit::Celsius, usa, english, UnitStyle::Long),
+            Some("68 degrees Fahrenheit".to_string())
+        );
+        assert_eq!(
+            format_measure(1.0, Unit::Pound, usa, english, UnitStyle::Long),
+            Some("1 pound".to_string())
+        );
+        assert_eq!(
+            format_measure(16.0, Unit::Ounce, usa, english, UnitStyle::Long),
+            Some("1 pound".to_string())
+        );
+        assert_eq!(
+            format_measure(500.0, Unit::Meter, france, french, UnitStyle::Long),
+            Some("500 mètres".to_string())
+        );
+        assert_eq!(
+            format_measure(1.5, Unit::Kilometer, france, french, UnitStyle::Long),
+            Some("1,5 kilomètre".to_string())
+        );
+        assert_eq!(
+            format_measure(0.5, Unit::Gallon, france, german, UnitStyle::Short),
+            Some("1,9 l".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_unit() {
+        let english = language::lookup("en").unwrap();
+        let german = language::lookup("de").unwrap();
+        let spanish = language::lookup("es").unwrap();
+        let japanese = language::lookup("ja").unwrap();
+
+        assert_eq!(
+            format_unit(12345.67, Unit::Meter, english, UnitStyle::Short),
+            Some("12,345.7 m".to_string())
+        );
+        assert_eq!(
+            format_unit(-3.0, Unit::Celsius, german, UnitStyle::Short),
+            Some("-3 °C".to_string())
+        );
+        assert_eq!(
+            format_unit(1234.0, Unit::Gram, spanish, UnitStyle::Short),
+            Some("1234 g".to_string())
+        );
+        assert_eq!(
+            format_unit(12345.0, Unit::Gram, spanish, UnitStyle::Short),
+            Some("12.345 g".to_string())
+        );
+        assert_eq!(
+            format_unit(2.0, Unit::Liter, japanese, UnitStyle::Long),
+            None
+        );
+    }
+
+    #[test]
+    fn test_format_non_finite() {
+        let usa = country::lookup("USA").unwrap();
+        let english = language::lookup("en").unwrap();
+
+        assert_eq!(
+            format_unit(std::f64::NAN, Unit::Meter, english, UnitStyle::Short),
+            None
+        );
+        assert_eq!(
+            format_unit(std::f64::INFINITY, Unit::Meter, english, UnitStyle::Long),
+            None
+        );
+        assert_eq!(
+            format_measure(
+                std::f64::NEG_INFINITY,
+                Unit::Celsius,
+                usa,
+                english,
+                UnitStyle::Short
+            ),
+            None
+        );
+        assert_eq!(
+            format_measure(
+                std::f64::NAN,
+                Unit::Kilometer,
+                usa,
+                english,
+                UnitStyle::Short
+            ),
+            None
+        );
+        assert_eq!(
+            format_unit(1e300, Unit::Meter, english, UnitStyle::Short),
+            None
+        );
+    }
+
+    #[test]
+    fn test_supported_languages() {
+        assert_eq!(supported_languages(), vec!["de", "en", "es", "fr"]);
+    }
+}
@@ -0,0 +1,346 @@
+/*!
+Postal code formats, and their validation, for each country.
+
+Most countries use postal codes, each with it's own format; for example 5
+digits in Germany, `A1A 1A1` in Canada, or the variable length outward and
+inward codes of the United Kingdom such as `SW1A 1AA`. Users enter postal
+codes in many ways, so codes are normalized before they are validated; they
+are upper-cased, and spaces or hyphens that are a part of the format are
+inserted where they were omitted, so `sw1a1aa` becomes `SW1A 1AA`.
+
+Countries that do not use postal codes have no `PostalCodeInfo`.
+
+## Source
+
+The data used here was compiled from the address metadata published by
+Google's [libaddressinput](https://github.com/google/libaddressinput) project.
+*/
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::country::CountryInfo;
+use crate::error::{check_alphabetic, LookupError};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A representation of the postal code format used within a country.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PostalCodeInfo {
+    /// The ISO-3166, part 1, 2-character identifier of the country.
+    pub country_code: String,
+    /// A regular expression matching a complete, normalized, postal code.
+    pub pattern: String,
+    /// Example postal codes, in normalized form.
+    pub examples: Vec<String>,
+    /// Whether a postal code is required in an address in this country.
+    pub required: bool,
+    /// A regular expression matching a postal code with all spaces and
+    /// hyphens removed, where these are a part of the normalized form.
+    pub compact_pattern: Option<String>,
+    /// The replacement used with `compact_pattern` to produce the normalized
+    /// form, for example `$1 $2`.
+    pub compact_template: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref POSTAL_CODES: HashMap<String, PostalCodeInfo> = load_postal_codes_from_json();
+    static ref PATTERNS: HashMap<String, (Regex, Option<Regex>)> = make_postal_code_patterns();
+}
+
+/// Lookup a `PostalCodeInfo` based on the country's ISO-3166 2-character
+/// identifier, returning `None` if the country does not use postal codes.
+pub fn lookup(code: &str) -> Option<&'static PostalCodeInfo> {
+    debug!("postal_code::lookup: {}", code);
+    POSTAL_CODES.get(code)
+}
+
+/// Lookup a `PostalCodeInfo` based on the country's ISO-3166 2-character
+/// identifier, returning a `LookupError` rather than `None` if the code is
+/// malformed or unknown.
+pub fn try_lookup(code: &str) -> Result<&'static PostalCodeInfo, LookupError> {
+    debug!("postal_code::try_lookup: {}", code);
+    check_alphabetic(
+        code,
+        &[2],
+        |code| code.to_ascii_uppercase(),
+        |code| lookup(code).is_some(),
+    )?;
+    match lookup(code) {
+        Some(info) => Ok(info),
+        None => Err(LookupError::unknown(
+            code,
+            POSTAL_CODES.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
+/// Return the postal code format used within the provided country, if any.
+pub fn for_country(country: &CountryInfo) -> Option<&'static PostalCodeInfo> {
+    lookup(&country.short_code)
+}
+
+/// Return all the ISO-3166 2-character country codes with postal codes.
+pub fn all_codes() -> Vec<String> {
+    POSTAL_CODES.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl PostalCodeInfo {
+    /// Normalize the provided postal code, returning `None` if it is not a
+    /// valid postal code for this country, or this format's patterns are
+    /// not valid regular expressions. Surrounding whitespace is removed,
+    /// letters are upper-cased, and any spaces or hyphens that are a part of
+    /// the format are inserted.
+    pub fn normalize(&self, postal_code: &str) -> Option<String> {
+        match PATTERNS.get(&self.country_code) {
+            Some((pattern, compact_pattern)) if self.is_registered() => {
+                self.normalize_with(postal_code, pattern, compact_pattern.as_ref())
+            }
+            _ => {
+                let pattern = anchored(&self.pattern).ok()?;
+                let compact_pattern = match &self.compact_pattern {
+                    Some(compact_pattern) => Some(anchored(compact_pattern).ok()?),
+                    None => None,
+                };
+                self.normalize_with(postal_code, &pattern, compact_pattern.as_ref())
+            }
+        }
+    }
+
+    /// Returns `true` if the provided postal code is valid for this country,
+    /// once normalized.
+    pub fn is_valid(&self, postal_code: &str) -> bool {
+        self.normalize(postal_code).is_some()
+    }
+
+    // The compiled patterns are cached only for the formats in the registry.
+    fn is_registered(&self) -> bool {
+        match POSTAL_CODES.get(&self.country_code) {
+            Some(info) => std::ptr::eq(info, self),
+            None => false,
+        }
+    }
+
+    fn normalize_with(
+        &self,
+        postal_code: &str,
+        pattern: &Regex,
+        compact_pattern: Option<&Regex>,
+    ) -> Option<String> {
+        let normalized = postal_code
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_uppercase();
+        let compact: String = normalized
+            .chars()
+            .filter(|c| !(*c == ' ' || *c == '-'))
+            .collect();
+        let normalized = match (compact_pattern, &self.compact_template) {
+            (Some(compact_pattern), Some(template)) if compact_pattern.is_match(&compact) => {
+                compact_pattern
+                    .replace(&compact, template.as_str())
+                    .to_string()
+            }
+            _ => normalized,
+        };
+        if pattern.is_match(&normalized) {
+            Some(normalized)
+        } else {
+            None
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn anchored(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{})$", pattern))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_postal_codes_from_json() -> HashMap<String, PostalCodeInfo> {
+    info!("load_postal_codes_from_json - loading JSON");
+    let raw_data = include_bytes!("data/postal_codes.json");
+    let postal_code_map: HashMap<String, PostalCodeInfo> =
+        serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_postal_codes_from_json - loaded {} postal code formats",
+        postal_code_map.len()
+    );
+    postal_code_map
+}
+
+fn make_postal_code_patterns() -> HashMap<String, (Regex, Option<Regex>)> {
+    info!("make_postal_code_patterns - create from POSTAL_CODES");
+    let pattern_map: HashMap<String, (Regex, Option<Regex>)> = POSTAL_CODES
+        .values()
+        .map(|info| {
+            (
+                info.country_code.to_string(),
+                (
+                    anchored(&info.pattern).unwrap(),
+                    info.compact_pattern.as_ref().map(|p| anchored(p).unwrap()),
+                ),
+            )
+        })
+        .collect();
+    info!(
+        "make_postal_code_patterns - compiled {} patterns",
+        pattern_map.len()
+    );
+    pattern_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::country;
+    use crate::error::LookupErrorKind;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_postal_code_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"GB".to_string()));
+        assert!(!codes.contains(&"HK".to_string()));
+    }
+
+    #[test]
+    fn test_good_postal_code_format() {
+        let germany = country::lookup("DEU").unwrap();
+        match for_country(germany) {
+            None => panic!("was expecting a postal code format"),
+            Some(info) => {
+                assert!(info.required);
+                assert_eq!(info.examples[0], "26133");
+                assert!(info.is_valid("10115"));
+                assert!(!info.is_valid("1011"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_examples_valid() {
+        for code in all_codes() {
+            let info = lookup(&code).unwrap();
+            for example in &info.examples {
+                assert_eq!(info.normalize(example), Some(example.to_string()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let gb = lookup("GB").unwrap();
+        assert_eq!(gb.normalize("sw1a1aa"), Some("SW1A 1AA".to_string()));
+        assert_eq!(gb.normalize("  ec1y   8sy "), Some("EC1Y 8SY".to_string()));
+        assert_eq!(gb.normalize("SW1A 1A"), None);
+
+        let ca = lookup("CA").unwrap();
+        assert_eq!(ca.normalize("h3z2y7"), Some("H3Z 2Y7".to_string()));
+        assert_eq!(ca.normalize("D3Z 2Y7"), None);
+
+        let us = lookup("US").unwrap();
+        assert_eq!(us.normalize("95014"), Some("95014".to_string()));
+        assert_eq!(us.normalize("221621010"), Some("22162-1010".to_string()));
+
+        let jp = lookup("JP").unwrap();
+        assert_eq!(jp.normalize("1540023"), Some("154-0023".to_string()));
+    }
+
+    #[test]
+    fn test_normalize_non_ascii_digits() {
+        let de = lookup("DE").unwrap();
+        assert_eq!(de.normalize("١٠١١٥"), None);
+        assert_eq!(de.normalize("１０１１５"), None);
+
+        let gb = lookup("GB").unwrap();
+        assert_eq!(gb.normalize("SW١A ١AA"), None);
+
+        let us = lookup("US").unwrap();
+        assert_eq!(us.normalize("９５０１４-１０１０"), None);
+    }
+
+    #[test]
+    fn test_bad_postal_code_country() {
+        match lookup("HK") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+    }
+
+    #[test]
+    fn test_normalize_own_patterns() {
+        let info = PostalCodeInfo {
+            country_code: "ZZ".to_string(),
+            pattern: "[0-9]{3} [0-9]{2}".to_string(),
+            examples: Vec::new(),
+            required: true,
+            compact_pattern: Some("([0-9]{3})([0-9]{2})".to_string()),
+            compact_template: Some("$1 $2".to_string()),
+        };
+        assert_eq!(info.normalize("12345"), Some("123 45".to_string()));
+        assert_eq!(info.normalize("1234"), None);
+
+        let info = PostalCodeInfo {
+            country_code: "DE".to_string(),
+            pattern: "[0-9]{4}".to_string(),
+            examples: Vec::new(),
+            required: true,
+            compact_pattern: None,
+            compact_template: None,
+        };
+        assert_eq!(info.normalize("1011"), Some("1011".to_string()));
+        assert_eq!(info.normalize("10115"), None);
+
+        let info = PostalCodeInfo {
+            country_code: "ZZ".to_string(),
+            pattern: "[0-9".to_string(),
+            examples: Vec::new(),
+            required: true,
+            compact_pattern: None,
+            compact_template: None,
+        };
+        assert_eq!(info.normalize("1"), None);
+    }
+
+    #[test]
+    fn test_try_postal_code_lookup() {
+        assert!(try_lookup("DE").is_ok());
+        assert_eq!(
+            try_lookup("de").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("D").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("QQ").unwrap_err().kind(),
+            LookupErrorKind::Unknown
+        );
+    }
+}
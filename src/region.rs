@@ -8,20 +8,109 @@ and subdivisions. However, it does not define the names of countries
 Bulletin Country Names and the Country and Region Codes for Statistical
 Use maintained by the United Nations Statistics Divisions).
 
+The regions form a hierarchy, as defined by the UN M49 standard, where
+each country is within an optional intermediate region, a sub-region, and
+a continent, all of which are within the World (1). For example Mexico (484)
+is in Central America (13), which is in Latin America and the Caribbean
+(419), which is in the Americas (19). The functions `parent`, `children`,
+`ancestors`, and `countries_in` navigate this hierarchy. Each `RegionInfo`
+has a `RegionKind` denoting its level in the hierarchy; entries that are
+countries, or areas, are linked to their `CountryInfo`.
+
+M49 also defines a number of special groupings of countries, used largely
+for development statistics, see `SpecialGrouping`. The functions
+`countries_in_grouping` and `is_in_grouping` query these groupings.
+
+Regions may also be found by name, using `lookup_by_name`, which accepts
+common aliases such as "LATAM"; where a name is ambiguous, or incomplete,
+`search` returns a ranked list of candidate regions.
+
+```rust
+use locale_codes::region;
+
+assert_eq!(region::lookup_by_name("Latin America & Caribbean").unwrap().code, 419);
+assert_eq!(region::search("west africa")[0].region.code, 11);
+```
+
+The M49 hierarchy is the default classification of countries, however a
+number of other organizations use their own schemes, see `Scheme`. The
+functions `scheme_groups`, `scheme_group_for`, and `countries_in_scheme_group`
+map countries to the groups of any scheme, including the M49 continents.
+
+```rust
+use locale_codes::{country, region};
+use locale_codes::region::Scheme;
+
+let chile = country::lookup("CL").unwrap();
+assert_eq!(region::scheme_group_for(chile, Scheme::M49).unwrap().name, "Americas");
+assert_eq!(region::scheme_group_for(chile, Scheme::GeoNames).unwrap().code, "SA");
+assert_eq!(region::scheme_group_for(chile, Scheme::WorldBankIncome).unwrap().code, "HIC");
+```
+
+```rust
+use locale_codes::{country, region};
+use locale_codes::region::SpecialGrouping;
+
+let nepal = country::lookup("NPL").unwrap();
+assert!(region::is_in_grouping(nepal, SpecialGrouping::LandLockedDeveloping));
+assert!(!region::is_in_grouping(nepal, SpecialGrouping::SmallIslandDeveloping));
+```
+
 ## Source - ISO 3166
 
 The data used here is taken from the page
 [Github](https://github.com/lukes/ISO-3166-Countries-with-Regional-Codes).
+
+## Source - Classification Schemes
+
+The groups of the other classification schemes were compiled from the
+published GeoNames, World Bank, and IMF country lists.
 */
 
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::country::{self, CountryInfo};
+use crate::error::{edit_distance, LookupError, LookupErrorKind};
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+/// The level of a region within the UN M49 hierarchy.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RegionKind {
+    /// The World, the root of the hierarchy.
+    World,
+    /// A continental region, such as Africa or the Americas.
+    Continent,
+    /// A sub-region of a continent, such as Western Europe.
+    SubRegion,
+    /// An intermediate region within a sub-region, such as Central America.
+    IntermediateRegion,
+    /// A country or area with an ISO 3166-1 code.
+    CountryOrArea,
+}
+
+/// A special grouping of countries defined by UN M49.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecialGrouping {
+    /// The Least Developed Countries (LDC).
+    LeastDeveloped,
+    /// The Land Locked Developing Countries (LLDC).
+    LandLockedDeveloping,
+    /// The Small Island Developing States (SIDS).
+    SmallIslandDeveloping,
+    /// Developed countries, those in Europe, Northern America, Australia and
+    /// New Zealand, and Japan.
+    Developed,
+    /// Developing countries, all those not considered developed.
+    Developing,
+}
+
 /// A representation of registered region data maintained by ISO.
 #[derive(Deserialize, Serialize, Debug)]
 pub struct RegionInfo {
@@ -29,6 +118,61 @@ pub struct RegionInfo {
     pub code: u16,
     /// The name of this region.
     pub name: String,
+    /// The level of this region within the hierarchy.
+    pub kind: RegionKind,
+    /// The numeric identifier of the region directly containing this one,
+    /// `None` only for the World.
+    pub parent_code: Option<u16>,
+    /// The ISO-3166, part 1, 3-character identifier of the country, where
+    /// this region is a country or area.
+    pub country_code: Option<String>,
+    /// The special groupings this country is a member of, empty if this
+    /// region is not a country or area.
+    pub groupings: Vec<SpecialGrouping>,
+}
+
+/// A scheme used to classify countries into regional, or economic, groups.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Scheme {
+    /// The UN M49 continents, codes such as `150` for Europe; the default.
+    M49,
+    /// The GeoNames continent codes `AF`, `AN`, `AS`, `EU`, `NA`, `OC`, and
+    /// `SA`.
+    GeoNames,
+    /// The World Bank regions, codes such as `SSF` for Sub-Saharan Africa.
+    WorldBankRegion,
+    /// The World Bank income groups `HIC`, `UMC`, `LMC`, and `LIC`.
+    WorldBankIncome,
+    /// The IMF World Economic Outlook groups, codes such as `AE` for the
+    /// advanced economies.
+    Imf,
+}
+
+/// A group of countries within a classification `Scheme`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SchemeGroupInfo {
+    /// The scheme this group is part of.
+    pub scheme: Scheme,
+    /// The identifier of this group within it's scheme.
+    pub code: String,
+    /// The name of this group, in English.
+    pub name: String,
+    /// The ISO-3166, part 1, 3-character identifiers of the member
+    /// countries.
+    pub members: Vec<String>,
+}
+
+/// A candidate region returned by `search`.
+#[derive(Debug, Clone)]
+pub struct RegionMatch {
+    /// The candidate region.
+    pub region: &'static RegionInfo,
+    /// The name, or alias, of the region that best matched the query.
+    pub name: &'static str,
+    /// How closely the name matched the query, from 0.0 to 1.0 where 1.0
+    /// denotes an exact match.
+    pub score: f32,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -37,6 +181,10 @@ pub struct RegionInfo {
 
 lazy_static! {
     static ref REGIONS: HashMap<u16, RegionInfo> = load_regions_from_json();
+    static ref CHILDREN: HashMap<u16, Vec<u16>> = make_region_children();
+    static ref NAMES: Vec<RegionName> = load_region_names_from_json();
+    static ref SCHEME_GROUPS: Vec<SchemeGroupInfo> = load_region_schemes_from_json();
+    static ref NAME_LOOKUP: HashMap<String, u16> = make_region_name_lookup();
 }
 
 /// Lookup a `RegionInfo` based on it's ISO-3166 numeric identifier, returning
@@ -49,11 +197,271 @@ pub fn lookup(code: u16) -> Option<&'static RegionInfo> {
     }
 }
 
+/// Lookup a `RegionInfo` based on it's numeric identifier, returning a
+/// `LookupError` rather than `None` if the code is unknown.
+pub fn try_lookup(code: u16) -> Result<&'static RegionInfo, LookupError> {
+    match lookup(code) {
+        Some(region) => Ok(region),
+        None => Err(LookupError::new(
+            LookupErrorKind::Unknown,
+            &code.to_string(),
+            Vec::new(),
+        )),
+    }
+}
+
+/// Lookup a `RegionInfo` based on it's name, or a common alias such as
+/// "LATAM", returning `None` if the name is not known. Names are compared
+/// ignoring case, diacritics, punctuation, and the word "the". Where the name
+/// is not that of a region, country names are also checked.
+pub fn lookup_by_name(name: &str) -> Option<&'static RegionInfo> {
+    debug!("region::lookup_by_name: {}", name);
+    match NAME_LOOKUP.get(&country::normalize_name(name)) {
+        Some(code) => lookup(*code),
+        None => match country::lookup_by_name(name) {
+            Some((country, _)) => for_country(country),
+            None => None,
+        },
+    }
+}
+
+/// Search for regions whose name, or alias, matches the query, returning
+/// candidates ordered from the best match. Each word in the query is matched
+/// against the start of words in the name, allowing for a single mistyped
+/// character in longer words; so "africa" returns Africa first, followed by
+/// each of the African sub-regions.
+pub fn search(query: &str) -> Vec<RegionMatch> {
+    debug!("region::search: {}", query);
+    let query = country::normalize_name(query);
+    let query_words: Vec<&str> = query.split_whitespace().collect();
+    let mut matches: Vec<RegionMatch> = Vec::new();
+    if query_words.is_empty() {
+        return matches;
+    }
+    for name in NAMES.iter() {
+        let score = name_score(&query, &query_words, &name.normalized);
+        if score <= 0.0 {
+            continue;
+        }
+        match matches.iter_mut().find(|m| m.region.code == name.code) {
+            Some(existing) => {
+                if score > existing.score {
+                    existing.name = &name.name;
+                    existing.score = score;
+                }
+            }
+            None => {
+                if let Some(region) = lookup(name.code) {
+                    matches.push(RegionMatch {
+                        region,
+                        name: &name.name,
+                        score,
+                    });
+                }
+            }
+        }
+    }
+    matches.sort_by(|lhs, rhs| {
+        rhs.score
+            .partial_cmp(&lhs.score)
+            .unwrap()
+            .then(lhs.region.code.cmp(&rhs.region.code))
+    });
+    matches
+}
+
+/// Lookup the `RegionInfo` for the provided country.
+pub fn for_country(country: &CountryInfo) -> Option<&'static RegionInfo> {
+    lookup(country.country_code)
+}
+
+/// Return the region that directly contains the identified region, returning
+/// `None` if the code does not exist or is the World.
+pub fn parent(code: u16) -> Option<&'static RegionInfo> {
+    match lookup(code) {
+        Some(region) => match region.parent_code {
+            Some(parent_code) => lookup(parent_code),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+/// Return the regions directly contained by the identified region, sorted by
+/// code. This will be empty if the code does not exist or if the region is
+/// a country.
+pub fn children(code: u16) -> Vec<&'static RegionInfo> {
+    match CHILDREN.get(&code) {
+        Some(child_codes) => child_codes.iter().filter_map(|c| lookup(*c)).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Return all the regions containing the identified region, from the
+/// nearest (its parent) to the top-level region.
+pub fn ancestors(code: u16) -> Vec<&'static RegionInfo> {
+    let mut ancestors: Vec<&'static RegionInfo> = Vec::new();
+    let mut current = parent(code);
+    while let Some(region) = current {
+        ancestors.push(region);
+        current = parent(region.code);
+    }
+    ancestors
+}
+
+/// Return all the countries within the identified region, at any level of
+/// the hierarchy, sorted by country code.
+pub fn countries_in(code: u16) -> Vec<&'static CountryInfo> {
+    let mut countries: Vec<&'static CountryInfo> = REGIONS
+        .values()
+        .filter(|region| region.kind == RegionKind::CountryOrArea)
+        .filter(|region| ancestors(region.code).iter().any(|r| r.code == code))
+        .filter_map(|region| region.country())
+        .collect();
+    countries.sort_by(|lhs, rhs| lhs.code.cmp(&rhs.code));
+    countries
+}
+
+/// Return all the countries in the provided special grouping, sorted by
+/// country code.
+pub fn countries_in_grouping(grouping: SpecialGrouping) -> Vec<&'static CountryInfo> {
+    let mut countries: Vec<&'static CountryInfo> = REGIONS
+        .values()
+        .filter(|region| region.groupings.contains(&grouping))
+        .filter_map(|region| region.country())
+        .collect();
+    countries.sort_by(|lhs, rhs| lhs.code.cmp(&rhs.code));
+    countries
+}
+
+/// Returns `true` if the provided country is in the provided special
+/// grouping.
+pub fn is_in_grouping(country: &CountryInfo, grouping: SpecialGrouping) -> bool {
+    match for_country(country) {
+        Some(region) => region.groupings.contains(&grouping),
+        None => false,
+    }
+}
+
+/// Return all the groups of the provided classification scheme.
+pub fn scheme_groups(scheme: Scheme) -> Vec<&'static SchemeGroupInfo> {
+    SCHEME_GROUPS
+        .iter()
+        .filter(|group| group.scheme == scheme)
+        .collect()
+}
+
+/// Lookup a `SchemeGroupInfo` based on it's scheme and identifier, returning
+/// `None` if the group is unknown.
+pub fn lookup_scheme_group(scheme: Scheme, code: &str) -> Option<&'static SchemeGroupInfo> {
+    debug!("region::lookup_scheme_group: {:?} {}", scheme, code);
+    SCHEME_GROUPS
+        .iter()
+        .find(|group| group.scheme == scheme && group.code == code)
+}
+
+/// Return the group the provided country is a member of in the provided
+/// classification scheme, returning `None` if the scheme does not cover the
+/// country.
+pub fn scheme_group_for(country: &CountryInfo, scheme: Scheme) -> Option<&'static SchemeGroupInfo> {
+    SCHEME_GROUPS
+        .iter()
+        .find(|group| group.scheme == scheme && group.is_member(country))
+}
+
+/// Return all the countries in the identified group of the provided
+/// classification scheme, sorted by country code.
+pub fn countries_in_scheme_group(scheme: Scheme, code: &str) -> Vec<&'static CountryInfo> {
+    match lookup_scheme_group(scheme, code) {
+        Some(group) => group
+            .members
+            .iter()
+            .filter_map(|member| country::lookup(member))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
 /// Return all the registered ISO-3166 numeric region codes.
 pub fn all_codes() -> Vec<u16> {
     REGIONS.keys().cloned().collect()
 }
 
+/// Return all the registered numeric region codes of the provided kind; for
+/// example all the continents.
+pub fn all_codes_of_kind(kind: RegionKind) -> Vec<u16> {
+    REGIONS
+        .values()
+        .filter(|region| region.kind == kind)
+        .map(|region| region.code)
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl RegionInfo {
+    /// Return the country this region represents, if it is a country or
+    /// area.
+    pub fn country(&self) -> Option<&'static CountryInfo> {
+        match &self.country_code {
+            Some(country_code) => country::lookup(country_code),
+            None => None,
+        }
+    }
+}
+
+impl Default for Scheme {
+    fn default() -> Self {
+        Scheme::M49
+    }
+}
+
+impl SchemeGroupInfo {
+    /// Returns `true` if the provided country is a member of this group.
+    pub fn is_member(&self, country: &CountryInfo) -> bool {
+        self.members.contains(&country.code)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+struct RegionName {
+    name: String,
+    code: u16,
+    #[serde(skip)]
+    normalized: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn name_score(query: &str, query_words: &[&str], name: &str) -> f32 {
+    if query == name {
+        return 1.0;
+    }
+    let name_words: Vec<&str> = name.split_whitespace().collect();
+    let matched = query_words
+        .iter()
+        .filter(|query_word| {
+            name_words
+                .iter()
+                .any(|name_word| word_matches(query_word, name_word))
+        })
+        .count() as f32;
+    0.9 * (matched / query_words.len() as f32) * (matched / name_words.len().max(1) as f32)
+}
+
+fn word_matches(query_word: &str, name_word: &str) -> bool {
+    name_word.starts_with(query_word)
+        || (query_word.chars().count() > 3 && edit_distance(query_word, name_word) <= 1)
+}
+
 // ------------------------------------------------------------------------------------------------
 // Generated Data
 // ------------------------------------------------------------------------------------------------
@@ -61,19 +469,73 @@ pub fn all_codes() -> Vec<u16> {
 fn load_regions_from_json() -> HashMap<u16, RegionInfo> {
     info!("load_regions_from_json - loading JSON");
     let raw_data = include_bytes!("data/regions.json");
-    let raw_map: HashMap<String, String> = serde_json::from_slice(raw_data).unwrap();
-    raw_map
-        .iter()
-        .map(|(code, name)| {
-            (
-                code.parse::<u16>().unwrap(),
-                RegionInfo {
-                    code: code.parse::<u16>().unwrap(),
-                    name: name.to_string(),
-                },
-            )
+    let region_map: HashMap<u16, RegionInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_regions_from_json - loaded {} regions",
+        region_map.len()
+    );
+    region_map
+}
+
+fn load_region_names_from_json() -> Vec<RegionName> {
+    info!("load_region_names_from_json - loading JSON");
+    let raw_data = include_bytes!("data/region_names.json");
+    let aliases: Vec<RegionName> = serde_json::from_slice(raw_data).unwrap();
+    let mut names: Vec<RegionName> = REGIONS
+        .values()
+        .map(|region| RegionName {
+            name: region.name.clone(),
+            code: region.code,
+            normalized: String::new(),
         })
-        .collect()
+        .chain(aliases)
+        .collect();
+    for name in names.iter_mut() {
+        name.normalized = country::normalize_name(&name.name);
+    }
+    info!("load_region_names_from_json - loaded {} names", names.len());
+    names
+}
+
+fn make_region_name_lookup() -> HashMap<String, u16> {
+    info!("make_region_name_lookup - create from NAMES");
+    let mut lookup_map: HashMap<String, u16> = HashMap::new();
+    for name in NAMES.iter() {
+        lookup_map
+            .entry(name.normalized.clone())
+            .or_insert(name.code);
+    }
+    info!(
+        "make_region_name_lookup - mapped {} names",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+fn load_region_schemes_from_json() -> Vec<SchemeGroupInfo> {
+    info!("load_region_schemes_from_json - loading JSON");
+    let raw_data = include_bytes!("data/region_schemes.json");
+    let groups: Vec<SchemeGroupInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_region_schemes_from_json - loaded {} groups",
+        groups.len()
+    );
+    groups
+}
+
+fn make_region_children() -> HashMap<u16, Vec<u16>> {
+    info!("make_region_children - create from REGIONS");
+    let mut child_map: HashMap<u16, Vec<u16>> = HashMap::new();
+    for region in REGIONS.values() {
+        if let Some(parent_code) = region.parent_code {
+            child_map.entry(parent_code).or_default().push(region.code);
+        }
+    }
+    for child_codes in child_map.values_mut() {
+        child_codes.sort();
+    }
+    info!("make_region_children - mapped {} regions", child_map.len());
+    child_map
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -99,6 +561,170 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_region_parent() {
+        assert_eq!(parent(276).unwrap().name, "Western Europe");
+        assert_eq!(parent(155).unwrap().name, "Europe");
+        assert_eq!(parent(150).unwrap().name, "World");
+        assert_eq!(parent(10).unwrap().name, "World");
+        assert!(parent(1).is_none());
+    }
+
+    #[test]
+    fn test_region_children() {
+        let codes: Vec<u16> = children(21).iter().map(|r| r.code).collect();
+        assert_eq!(codes, vec![60, 124, 304, 666, 840]);
+        assert!(children(276).is_empty());
+    }
+
+    #[test]
+    fn test_region_ancestors() {
+        let codes: Vec<u16> = ancestors(484).iter().map(|r| r.code).collect();
+        assert_eq!(codes, vec![13, 419, 19, 1]);
+    }
+
+    #[test]
+    fn test_countries_in_region() {
+        let europe = countries_in(150);
+        assert!(europe.iter().any(|c| c.code == "DEU"));
+        assert!(!europe.iter().any(|c| c.code == "MEX"));
+        let latin_america = countries_in(419);
+        assert!(latin_america.iter().any(|c| c.code == "MEX"));
+        assert!(latin_america.iter().any(|c| c.code == "BRA"));
+        assert!(countries_in(0).is_empty());
+        assert_eq!(countries_in(1).len(), 249);
+        assert!(countries_in(484).is_empty());
+    }
+
+    #[test]
+    fn test_region_kinds() {
+        assert_eq!(lookup(1).unwrap().kind, RegionKind::World);
+        assert_eq!(lookup(150).unwrap().kind, RegionKind::Continent);
+        assert_eq!(lookup(155).unwrap().kind, RegionKind::SubRegion);
+        assert_eq!(lookup(13).unwrap().kind, RegionKind::IntermediateRegion);
+
+        let afghanistan = lookup(4).unwrap();
+        assert_eq!(afghanistan.kind, RegionKind::CountryOrArea);
+        assert_eq!(afghanistan.country().unwrap().code, "AFG");
+        assert!(lookup(142).unwrap().country().is_none());
+
+        let mexico = country::lookup("MEX").unwrap();
+        assert_eq!(for_country(mexico).unwrap().code, 484);
+    }
+
+    #[test]
+    fn test_special_groupings() {
+        assert_eq!(
+            countries_in_grouping(SpecialGrouping::LeastDeveloped).len(),
+            44
+        );
+        assert_eq!(
+            countries_in_grouping(SpecialGrouping::LandLockedDeveloping).len(),
+            32
+        );
+        assert_eq!(
+            countries_in_grouping(SpecialGrouping::SmallIslandDeveloping).len(),
+            58
+        );
+
+        let haiti = country::lookup("HTI").unwrap();
+        assert!(is_in_grouping(haiti, SpecialGrouping::LeastDeveloped));
+        assert!(is_in_grouping(
+            haiti,
+            SpecialGrouping::SmallIslandDeveloping
+        ));
+        assert!(is_in_grouping(haiti, SpecialGrouping::Developing));
+        assert!(!is_in_grouping(haiti, SpecialGrouping::Developed));
+
+        let japan = country::lookup("JPN").unwrap();
+        assert_eq!(
+            for_country(japan).unwrap().groupings,
+            vec![SpecialGrouping::Developed]
+        );
+
+        let antarctica = country::lookup("ATA").unwrap();
+        assert!(for_country(antarctica).unwrap().groupings.is_empty());
+        assert!(lookup(150).unwrap().groupings.is_empty());
+    }
+
+    #[test]
+    fn test_region_lookup_by_name() {
+        assert_eq!(lookup_by_name("Sub-Saharan Africa").unwrap().code, 202);
+        assert_eq!(lookup_by_name("sub saharan africa").unwrap().code, 202);
+        assert_eq!(
+            lookup_by_name("Latin America & Caribbean").unwrap().code,
+            419
+        );
+        assert_eq!(lookup_by_name("LATAM").unwrap().code, 419);
+        assert_eq!(lookup_by_name("the Americas").unwrap().code, 19);
+        assert_eq!(lookup_by_name("Mexico").unwrap().code, 484);
+        assert_eq!(lookup_by_name("UK").unwrap().code, 826);
+        assert!(lookup_by_name("Atlantis").is_none());
+    }
+
+    #[test]
+    fn test_region_search() {
+        let matches = search("Africa");
+        assert_eq!(matches[0].region.code, 2);
+        assert_eq!(matches[0].score, 1.0);
+        assert!(matches.iter().any(|m| m.region.code == 202));
+        assert!(matches.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let matches = search("Caribean");
+        assert_eq!(matches[0].region.code, 29);
+
+        let matches = search("latin amer");
+        assert_eq!(matches[0].region.code, 419);
+        assert_eq!(matches[0].name, "Latin America");
+
+        assert!(search("").is_empty());
+        assert!(search("xyzzy").is_empty());
+    }
+
+    #[test]
+    fn test_region_schemes() {
+        assert_eq!(Scheme::default(), Scheme::M49);
+        assert_eq!(scheme_groups(Scheme::M49).len(), 5);
+        assert_eq!(scheme_groups(Scheme::GeoNames).len(), 7);
+        assert_eq!(scheme_groups(Scheme::WorldBankRegion).len(), 7);
+        assert_eq!(scheme_groups(Scheme::WorldBankIncome).len(), 4);
+
+        let turkey = country::lookup("TUR").unwrap();
+        assert_eq!(scheme_group_for(turkey, Scheme::M49).unwrap().code, "142");
+        assert_eq!(
+            scheme_group_for(turkey, Scheme::GeoNames).unwrap().code,
+            "AS"
+        );
+        assert_eq!(
+            scheme_group_for(turkey, Scheme::WorldBankRegion)
+                .unwrap()
+                .code,
+            "ECS"
+        );
+        assert_eq!(scheme_group_for(turkey, Scheme::Imf).unwrap().code, "EDE");
+
+        let antarctica = country::lookup("ATA").unwrap();
+        assert!(scheme_group_for(antarctica, Scheme::M49).is_none());
+        assert_eq!(
+            scheme_group_for(antarctica, Scheme::GeoNames).unwrap().code,
+            "AN"
+        );
+
+        let north_america = countries_in_scheme_group(Scheme::WorldBankRegion, "NAC");
+        let codes: Vec<&str> = north_america.iter().map(|c| c.code.as_str()).collect();
+        assert_eq!(codes, vec!["BMU", "CAN", "USA"]);
+        assert!(countries_in_scheme_group(Scheme::Imf, "XX").is_empty());
+    }
+
+    #[test]
+    fn test_region_codes_of_kind() {
+        let mut continents = all_codes_of_kind(RegionKind::Continent);
+        continents.sort();
+        assert_eq!(continents, vec![2, 9, 19, 142, 150]);
+        assert_eq!(all_codes_of_kind(RegionKind::World), vec![1]);
+        assert_eq!(all_codes_of_kind(RegionKind::CountryOrArea).len(), 249);
+    }
+
     #[test]
     fn test_bad_region_code() {
         match lookup(0) {
@@ -106,4 +732,12 @@ mod tests {
             Some(_) => panic!("was expecting a None in response"),
         }
     }
+
+    #[test]
+    fn test_try_region_lookup() {
+        assert_eq!(try_lookup(150).unwrap().name, "Europe");
+        let error = try_lookup(0).unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert_eq!(error.code(), "0");
+    }
 }
@@ -31,6 +31,9 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::display_name::{self, NameForm};
+use crate::error::{check_alphabetic, LookupError, LookupErrorKind};
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -58,7 +61,8 @@ lazy_static! {
 }
 
 /// Lookup a `ScriptInfo` based on it's ISO-15924 4-character identifier, returning
-/// `None` if the name does not exist in the current ISO data set.
+/// `None` if the name does not exist in the current ISO data set. This will
+/// panic if the code is not 4 characters long; see `try_lookup_by_alpha`.
 pub fn lookup_by_alpha(alphabetic_code: &str) -> Option<&'static ScriptInfo> {
     assert_eq!(
         alphabetic_code.len(),
@@ -68,6 +72,40 @@ pub fn lookup_by_alpha(alphabetic_code: &str) -> Option<&'static ScriptInfo> {
     SCRIPTS.get(alphabetic_code)
 }
 
+/// Lookup a `ScriptInfo` based on it's ISO-15924 4-character identifier, returning
+/// a `LookupError` rather than panicking if the code is malformed. Codes are
+/// expected in title case, such as `Latn`; the deprecated codes `Qaac` and
+/// `Qaai` are reported with their replacements.
+pub fn try_lookup_by_alpha(alphabetic_code: &str) -> Result<&'static ScriptInfo, LookupError> {
+    debug!("script::try_lookup_by_alpha: {}", alphabetic_code);
+    check_alphabetic(
+        alphabetic_code,
+        &[4],
+        |code| {
+            let (first, rest) = code.split_at(1);
+            first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+        },
+        |code| SCRIPTS.contains_key(code),
+    )?;
+    if let Some(script) = SCRIPTS.get(alphabetic_code) {
+        return Ok(script);
+    }
+    match DEPRECATED_CODES
+        .iter()
+        .find(|(deprecated, _)| *deprecated == alphabetic_code)
+    {
+        Some((_, replacement)) => Err(LookupError::new(
+            LookupErrorKind::Withdrawn,
+            alphabetic_code,
+            vec![replacement.to_string()],
+        )),
+        None => Err(LookupError::unknown(
+            alphabetic_code,
+            SCRIPTS.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
 /// Lookup a `ScriptInfo` based on it's ISO-15924 numeric identifier, returning
 /// `None` if the name does not exist in the current ISO data set.
 pub fn lookup_by_numeric(numeric_code: &u16) -> Option<&'static ScriptInfo> {
@@ -77,6 +115,32 @@ pub fn lookup_by_numeric(numeric_code: &u16) -> Option<&'static ScriptInfo> {
     }
 }
 
+/// Return the name of the identified script, in the language of the
+/// provided locale identifier, for example `Lateinisch` for `Latn` in `de`;
+/// see [`display_name`](../display_name/index.html). Returns `None` if the
+/// code is not a registered script or names are not available in the
+/// language.
+pub fn display_name(alphabetic_code: &str, in_language: &str) -> Option<&'static str> {
+    display_name_with_form(alphabetic_code, in_language, NameForm::Standard)
+}
+
+/// Return the name of the identified script, in the provided form, in the
+/// language of the provided locale identifier; for example `Perso-Arabic`
+/// is the variant form for `Arab` in `en`.
+pub fn display_name_with_form(
+    alphabetic_code: &str,
+    in_language: &str,
+    form: NameForm,
+) -> Option<&'static str> {
+    if alphabetic_code.len() != 4 {
+        return None;
+    }
+    match lookup_by_alpha(alphabetic_code) {
+        Some(script) => display_name::script_name(&script.alphabetic_code, in_language, form),
+        None => None,
+    }
+}
+
 /// Return all the registered ISO-15924 4-character country codes.
 pub fn all_alpha_codes() -> Vec<String> {
     SCRIPTS.keys().cloned().collect()
@@ -87,6 +151,13 @@ pub fn all_numeric_codes() -> Vec<u16> {
     NUMERIC_LOOKUP.keys().cloned().collect()
 }
 
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+/// Codes deprecated in ISO-15924, and their replacements.
+const DEPRECATED_CODES: &[(&str, &str)] = &[("Qaac", "Copt"), ("Qaai", "Zinh")];
+
 // ------------------------------------------------------------------------------------------------
 // Generated Data
 // ------------------------------------------------------------------------------------------------
@@ -166,4 +237,32 @@ mod tests {
         let numerics = all_numeric_codes();
         assert!(numerics.len() > 0);
     }
+
+    #[test]
+    fn test_script_display_name() {
+        assert_eq!(display_name("Latn", "de"), Some("Lateinisch"));
+        assert_eq!(display_name("Cyrl", "en"), Some("Cyrillic"));
+        assert_eq!(display_name("Latin", "de"), None);
+    }
+
+    #[test]
+    fn test_try_script_lookup() {
+        assert_eq!(try_lookup_by_alpha("Latn").unwrap().numeric_code, 215);
+        assert_eq!(
+            try_lookup_by_alpha("Lat").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup_by_alpha("LATN").unwrap_err().suggestions(),
+            ["Latn"]
+        );
+
+        let error = try_lookup_by_alpha("Qaai").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Withdrawn);
+        assert_eq!(error.suggestions(), ["Zinh"]);
+        assert_eq!(
+            try_lookup_by_alpha("Latx").unwrap_err().kind(),
+            LookupErrorKind::Unknown
+        );
+    }
 }
@@ -0,0 +1,339 @@
+/*!
+Codes for the representation of names of countries and their subdivisions
+– Part 2: Country subdivision code.
+
+ISO 3166-2 defines codes for identifying the principal subdivisions (e.g.,
+provinces or states) of all countries coded in ISO 3166-1. Each complete
+code consists of two parts, separated by a hyphen; the first part is the
+ISO 3166-1 2-character code of the country, and the second part is a string
+of up to three alphanumeric characters. For example `US-CA` identifies the
+state of California in the United States.
+
+Subdivisions may be arranged hierarchically, the counties of England (such
+as `GB-KEN`) all have the parent subdivision `GB-ENG`.
+
+Unicode defines flag emoji for subdivisions as tag sequences, although only
+those for England, Scotland, and Wales are recommended for general
+interchange; these are available from `SubdivisionInfo::flag_emoji`.
+
+## Source - ISO 3166-2
+
+The data used here is taken from the Debian
+[iso-codes](https://salsa.debian.org/iso-codes-team/iso-codes) project,
+which also provides translations for many of the subdivision names.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::CountryInfo;
+use crate::error::{LookupError, LookupErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A representation of registered country subdivision data maintained by ISO.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubdivisionInfo {
+    /// The complete ISO-3166-2 identifier of the subdivision, for example
+    /// `US-CA`.
+    pub code: String,
+    /// The ISO-3166, part 1, 2-character identifier of the country this
+    /// subdivision belongs to.
+    pub country_code: String,
+    /// The name of this subdivision, usually in a language of the country.
+    pub name: String,
+    /// The category of this subdivision, for example "State", "Province",
+    /// or "Canton".
+    pub category: String,
+    /// The complete ISO-3166-2 identifier of the parent subdivision, if
+    /// this subdivision is a part of another.
+    pub parent_code: Option<String>,
+    /// Translations of `name`, keyed by locale identifier (for example
+    /// `de` or `zh_CN`), where they differ from the registered name.
+    pub localized_names: HashMap<String, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref SUBDIVISIONS: HashMap<String, SubdivisionInfo> = load_subdivisions_from_json();
+    static ref COUNTRY_LOOKUP: HashMap<String, Vec<String>> = make_subdivision_lookup();
+}
+
+/// Lookup a `SubdivisionInfo` based on it's complete ISO-3166-2 identifier,
+/// returning `None` if the code does not exist in the current ISO data set.
+pub fn lookup(code: &str) -> Option<&'static SubdivisionInfo> {
+    debug!("subdivision::lookup: {}", code);
+    SUBDIVISIONS.get(code)
+}
+
+/// Lookup a `SubdivisionInfo` based on it's complete ISO-3166-2 identifier,
+/// returning a `LookupError` rather than `None` if the code is malformed or
+/// unknown. Codes are expected to be upper case, such as `GB-ENG`.
+pub fn try_lookup(code: &str) -> Result<&'static SubdivisionInfo, LookupError> {
+    debug!("subdivision::try_lookup: {}", code);
+    let well_formed = code.is_ascii()
+        && code.len() >= 4
+        && code.len() <= 6
+        && code[..2].chars().all(|c| c.is_ascii_alphabetic())
+        && &code[2..3] == "-"
+        && code[3..].chars().all(|c| c.is_ascii_alphanumeric());
+    if !well_formed {
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            code,
+            Vec::new(),
+        ));
+    }
+    let upper_case = code.to_ascii_uppercase();
+    if upper_case != code {
+        let suggestions = match lookup(&upper_case) {
+            Some(_) => vec![upper_case],
+            None => Vec::new(),
+        };
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            code,
+            suggestions,
+        ));
+    }
+    match lookup(code) {
+        Some(subdivision) => Ok(subdivision),
+        None => Err(LookupError::unknown(
+            code,
+            SUBDIVISIONS
+                .keys()
+                .map(|k| k.as_str())
+                .filter(|k| k.starts_with(&code[..3])),
+        )),
+    }
+}
+
+/// Return all the subdivisions of the provided country, sorted by code.
+/// This includes subdivisions at every level of the hierarchy.
+pub fn subdivisions_of(country: &CountryInfo) -> Vec<&'static SubdivisionInfo> {
+    match COUNTRY_LOOKUP.get(&country.short_code) {
+        Some(codes) => codes.iter().filter_map(|code| lookup(code)).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Return the parent of the identified subdivision, returning `None` if the
+/// code does not exist or if the subdivision has no parent.
+pub fn parent(code: &str) -> Option<&'static SubdivisionInfo> {
+    match lookup(code) {
+        Some(subdivision) => match &subdivision.parent_code {
+            Some(parent_code) => lookup(parent_code),
+            None => None,
+        },
+        None => None,
+    }
+}
+
+/// Returns `true` if the code identifies a registered subdivision of the
+/// provided country.
+pub fn is_subdivision_of(code: &str, country: &CountryInfo) -> bool {
+    match lookup(code) {
+        Some(subdivision) => subdivision.country_code == country.short_code,
+        None => false,
+    }
+}
+
+/// Lookup a `SubdivisionInfo` based on a flag emoji tag sequence, returning
+/// `None` if the string is not a tag sequence, or is not one of the flags
+/// returned by `SubdivisionInfo::flag_emoji`.
+pub fn lookup_by_flag(flag: &str) -> Option<&'static SubdivisionInfo> {
+    debug!("subdivision::lookup_by_flag: {}", flag);
+    let chars: Vec<char> = flag.chars().collect();
+    if chars.len() < 4 || chars[0] != BLACK_FLAG || chars[chars.len() - 1] != CANCEL_TAG {
+        return None;
+    }
+    let mut tags = String::new();
+    for c in &chars[1..chars.len() - 1] {
+        let c = *c as u32;
+        if c < TAG_BASE + 0x20 || c > TAG_BASE + 0x7E {
+            return None;
+        }
+        match std::char::from_u32(c - TAG_BASE) {
+            Some(tag) if tag.is_ascii_alphanumeric() => tags.push(tag.to_ascii_uppercase()),
+            _ => return None,
+        }
+    }
+    let code = format!("{}-{}", &tags[..2], &tags[2..]);
+    match lookup(&code) {
+        Some(subdivision) if subdivision.flag_emoji().is_some() => Some(subdivision),
+        _ => None,
+    }
+}
+
+/// Return all the registered ISO-3166-2 subdivision codes.
+pub fn all_codes() -> Vec<String> {
+    SUBDIVISIONS.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SubdivisionInfo {
+    /// Return the flag emoji tag sequence for this subdivision, if Unicode
+    /// recommends one for general interchange; currently only England
+    /// (`GB-ENG`), Scotland (`GB-SCT`), and Wales (`GB-WLS`).
+    pub fn flag_emoji(&self) -> Option<String> {
+        if !FLAG_SUBDIVISIONS.contains(&self.code.as_str()) {
+            return None;
+        }
+        let mut flag = String::new();
+        flag.push(BLACK_FLAG);
+        for c in self.code.chars().filter(|c| *c != '-') {
+            flag.extend(std::char::from_u32(
+                TAG_BASE + c.to_ascii_lowercase() as u32,
+            ));
+        }
+        flag.push(CANCEL_TAG);
+        Some(flag)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const FLAG_SUBDIVISIONS: [&str; 3] = ["GB-ENG", "GB-SCT", "GB-WLS"];
+
+const BLACK_FLAG: char = '\u{1F3F4}';
+
+const CANCEL_TAG: char = '\u{E007F}';
+
+const TAG_BASE: u32 = 0xE0000;
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_subdivisions_from_json() -> HashMap<String, SubdivisionInfo> {
+    info!("load_subdivisions_from_json - loading JSON");
+    let raw_data = include_bytes!("data/subdivisions.json");
+    let subdivision_map: HashMap<String, SubdivisionInfo> =
+        serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_subdivisions_from_json - loaded {} subdivisions",
+        subdivision_map.len()
+    );
+    subdivision_map
+}
+
+fn make_subdivision_lookup() -> HashMap<String, Vec<String>> {
+    info!("make_subdivision_lookup - create from SUBDIVISIONS");
+    let mut lookup_map: HashMap<String, Vec<String>> = HashMap::new();
+    for subdivision in SUBDIVISIONS.values() {
+        lookup_map
+            .entry(subdivision.country_code.to_string())
+            .or_default()
+            .push(subdivision.code.to_string());
+    }
+    for codes in lookup_map.values_mut() {
+        codes.sort();
+    }
+    info!(
+        "make_subdivision_lookup - mapped {} countries",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::country;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_subdivision_codes() {
+        let codes = all_codes();
+        assert!(!codes.is_empty());
+    }
+
+    #[test]
+    fn test_good_subdivision_code() {
+        match lookup("US-CA") {
+            None => panic!("was expecting a subdivision"),
+            Some(subdivision) => {
+                assert_eq!(subdivision.country_code, "US");
+                assert_eq!(subdivision.name, "California");
+                assert_eq!(subdivision.category, "State");
+                assert_eq!(subdivision.parent_code, None);
+                assert_eq!(
+                    subdivision.localized_names.get("de"),
+                    Some(&"Kalifornien".to_string())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bad_subdivision_code() {
+        match lookup("US-XX") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+    }
+
+    #[test]
+    fn test_subdivision_parent() {
+        match parent("GB-KEN") {
+            None => panic!("was expecting a parent subdivision"),
+            Some(subdivision) => assert_eq!(subdivision.code, "GB-ENG"),
+        }
+        assert!(parent("US-CA").is_none());
+    }
+
+    #[test]
+    fn test_subdivision_flag() {
+        let wales = lookup("GB-WLS").unwrap();
+        let flag = wales.flag_emoji().unwrap();
+        assert_eq!(
+            flag,
+            "\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}"
+        );
+        assert_eq!(lookup_by_flag(&flag).unwrap().code, "GB-WLS");
+        assert!(lookup("US-CA").unwrap().flag_emoji().is_none());
+        assert!(lookup_by_flag("\u{1F3F4}").is_none());
+        assert!(lookup_by_flag("\u{1F3F4}ab\u{E007F}").is_none());
+    }
+
+    #[test]
+    fn test_subdivisions_of_country() {
+        let canada = country::lookup("CA").unwrap();
+        let provinces = subdivisions_of(canada);
+        assert_eq!(provinces.len(), 13);
+        assert!(provinces.iter().all(|p| p.country_code == "CA"));
+        assert!(is_subdivision_of("CA-QC", canada));
+        assert!(!is_subdivision_of("US-CA", canada));
+    }
+
+    #[test]
+    fn test_try_subdivision_lookup() {
+        assert_eq!(try_lookup("GB-ENG").unwrap().code, "GB-ENG");
+        assert_eq!(
+            try_lookup("GBENG").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        let error = try_lookup("gb-eng").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Malformed);
+        assert_eq!(error.suggestions(), ["GB-ENG"]);
+        let error = try_lookup("GB-ENX").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert!(error.suggestions().contains(&"GB-ENG".to_string()));
+    }
+}
@@ -0,0 +1,801 @@
+/*!
+Validation of VAT numbers, and other national tax identifiers, for each
+country.
+
+Each scheme describes one kind of identifier issued within a country, for
+example the German _Umsatzsteuer-Identifikationsnummer_ or the Australian
+Business Number; it's structure, the prefix used (if any), and the check
+digit algorithm. Validation is only structural; no online service, such as
+the EU's VIES, is consulted. A few formats have no check digit at all, Czech
+birth numbers issued before 1954, Latvian personal codes issued since 2017,
+and UK government department and health authority numbers; these are
+reported by `TaxId::check_digit_verified`.
+
+EU VAT numbers are prefixed with a country code, which is the ISO 3166-1
+code except for Greece, which uses `EL`, and Northern Ireland, which uses
+`XI` for goods under the Windsor Framework. The issuing country of a
+validated number is always the ISO country.
+
+```rust
+use locale_codes::tax_id;
+
+let vat = tax_id::validate_vat("EL 094 259 216").unwrap();
+assert_eq!(vat.value, "EL094259216");
+assert_eq!(vat.country_code, "GR");
+assert!(tax_id::validate_vat("EL094259215").is_none());
+```
+
+## Source
+
+The data used here was compiled from the VAT number formats published by the
+European Commission, and the algorithms published by national tax
+authorities.
+*/
+
+use std::collections::HashMap;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::country::{self, CountryInfo};
+use crate::error::{LookupError, LookupErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The check digit algorithm used by a tax identifier scheme.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxIdChecksum {
+    /// The Australian Business Number weighted modulus 89 check.
+    Australia,
+    /// The Austrian UID check digit.
+    Austria,
+    /// The Belgian modulus 97 check.
+    Belgium,
+    /// The Bulgarian legal entity, and personal number (EGN), check digits.
+    Bulgaria,
+    /// The Canadian Business Number, the Luhn algorithm over the first 9
+    /// digits.
+    Canada,
+    /// The Cypriot check letter.
+    Cyprus,
+    /// The Czech legal entity, and birth number, check digits.
+    Czechia,
+    /// The Danish weighted modulus 11 check.
+    Denmark,
+    /// The Estonian weighted modulus 10 check digit.
+    Estonia,
+    /// The Finnish weighted modulus 11 check digit.
+    Finland,
+    /// The French check key, derived from the SIREN.
+    France,
+    /// The Greek weighted modulus 11 check digit.
+    Greece,
+    /// The Hungarian weighted modulus 10 check digit.
+    Hungary,
+    /// The Indian GSTIN modulus 36 check character.
+    India,
+    /// The Irish modulus 23 check letter.
+    Ireland,
+    /// ISO 7064 MOD 11,10; as used in Germany and Croatia.
+    Iso7064Mod11_10,
+    /// The Latvian legal entity check digit.
+    Latvia,
+    /// The Lithuanian weighted modulus 11 check digit.
+    Lithuania,
+    /// The Luhn algorithm; as used in Italy.
+    Luhn,
+    /// The Luxembourg modulus 89 check.
+    Luxembourg,
+    /// The Maltese weighted modulus 37 check.
+    Malta,
+    /// The Dutch weighted modulus 11 check, or modulus 97 check for sole
+    /// proprietors.
+    Netherlands,
+    /// The New Zealand IRD number weighted modulus 11 check digit.
+    NewZealand,
+    /// The Norwegian organization number weighted modulus 11 check digit.
+    Norway,
+    /// The Polish weighted modulus 11 check digit.
+    Poland,
+    /// The Portuguese weighted modulus 11 check digit.
+    Portugal,
+    /// The Romanian weighted modulus 11 check digit.
+    Romania,
+    /// The Slovak modulus 11 check.
+    Slovakia,
+    /// The Slovenian weighted modulus 11 check digit.
+    Slovenia,
+    /// The Spanish NIF check letter or digit.
+    Spain,
+    /// The Swedish organization number, the Luhn algorithm over the first 10
+    /// digits.
+    Sweden,
+    /// The Swiss UID weighted modulus 11 check digit.
+    Switzerland,
+    /// The United Kingdom weighted modulus 97 check.
+    UnitedKingdom,
+}
+
+/// A representation of a tax identifier scheme used within a country.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TaxIdSchemeInfo {
+    /// The identifier of the scheme, for example `DE-VAT` or `AU-ABN`.
+    pub code: String,
+    /// The ISO-3166, part 1, 2-character identifier of the issuing country.
+    pub country_code: String,
+    /// The complete ISO-3166-2 identifier of the subdivision this scheme
+    /// applies to, if any; for example `GB-NIR` for Northern Ireland.
+    pub subdivision_code: Option<String>,
+    /// The name of the identifier, in a language of the country.
+    pub name: String,
+    /// The prefix written before the number, if any; for example `EL` for
+    /// Greek VAT numbers.
+    pub prefix: Option<String>,
+    /// A regular expression matching the number, without the prefix.
+    pub pattern: String,
+    /// The check digit algorithm.
+    pub checksum: TaxIdChecksum,
+    /// Example valid numbers, without the prefix.
+    pub examples: Vec<String>,
+}
+
+/// A validated tax identifier.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TaxId {
+    /// The identifier of the scheme the number was issued under.
+    pub scheme_code: String,
+    /// The ISO-3166, part 1, 2-character identifier of the issuing country.
+    pub country_code: String,
+    /// The complete ISO-3166-2 identifier of the subdivision, if the scheme
+    /// applies to one.
+    pub subdivision_code: Option<String>,
+    /// The normalized number, without the prefix.
+    pub number: String,
+    /// The normalized number, with the scheme's prefix if it has one.
+    pub value: String,
+    /// `false` if the number's format has no check digit, so only it's
+    /// structure was validated.
+    pub check_digit_verified: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref TAX_ID_SCHEMES: HashMap<String, TaxIdSchemeInfo> = load_tax_id_schemes_from_json();
+    static ref PATTERNS: HashMap<String, Regex> = make_tax_id_patterns();
+}
+
+/// Lookup a `TaxIdSchemeInfo` based on it's identifier, returning `None` if
+/// the scheme is unknown.
+pub fn lookup(code: &str) -> Option<&'static TaxIdSchemeInfo> {
+    debug!("tax_id::lookup: {}", code);
+    TAX_ID_SCHEMES.get(code)
+}
+
+/// Lookup a `TaxIdSchemeInfo` based on it's identifier, returning a
+/// `LookupError` rather than `None` if the identifier is empty or unknown.
+pub fn try_lookup(code: &str) -> Result<&'static TaxIdSchemeInfo, LookupError> {
+    debug!("tax_id::try_lookup: {}", code);
+    if code.trim().is_empty() {
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            code,
+            Vec::new(),
+        ));
+    }
+    match lookup(code) {
+        Some(info) => Ok(info),
+        None => Err(LookupError::unknown(
+            code,
+            TAX_ID_SCHEMES.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
+/// Return the tax identifier schemes used within the provided country;
+/// country-wide schemes first, then those for a subdivision, each sorted by
+/// code.
+pub fn schemes_for_country(country: &CountryInfo) -> Vec<&'static TaxIdSchemeInfo> {
+    let mut schemes: Vec<&'static TaxIdSchemeInfo> = TAX_ID_SCHEMES
+        .values()
+        .filter(|scheme| scheme.country_code == country.short_code)
+        .collect();
+    schemes.sort_by(|lhs, rhs| {
+        (lhs.subdivision_code.is_some(), &lhs.code)
+            .cmp(&(rhs.subdivision_code.is_some(), &rhs.code))
+    });
+    schemes
+}
+
+/// Validate a tax identifier issued within the provided country, with or
+/// without it's prefix, returning `None` if it is not valid for any of the
+/// country's schemes.
+pub fn validate(tax_id: &str, country: &CountryInfo) -> Option<TaxId> {
+    schemes_for_country(country)
+        .iter()
+        .find_map(|scheme| scheme.normalize(tax_id))
+}
+
+/// Validate a prefixed VAT number, such as `DE136695976` or `EL094259216`,
+/// returning `None` if the prefix is unknown or the number is not valid.
+/// Note that Greek numbers must use the `EL` prefix, not `GR`.
+pub fn validate_vat(vat_number: &str) -> Option<TaxId> {
+    let value = normalized(vat_number);
+    TAX_ID_SCHEMES
+        .values()
+        .find_map(|scheme| match &scheme.prefix {
+            Some(prefix) if value.starts_with(prefix.as_str()) => {
+                scheme.parse_number(&value[prefix.len()..])
+            }
+            _ => None,
+        })
+}
+
+/// Return the country that issues VAT numbers with the provided prefix;
+/// for example `EL` returns Greece, and `XI` returns the United Kingdom.
+pub fn country_for_prefix(prefix: &str) -> Option<&'static CountryInfo> {
+    TAX_ID_SCHEMES
+        .values()
+        .find(|scheme| scheme.prefix.as_ref().map(String::as_str) == Some(prefix))
+        .and_then(|scheme| country::lookup(&scheme.country_code))
+}
+
+/// Return all the tax identifier scheme identifiers.
+pub fn all_codes() -> Vec<String> {
+    TAX_ID_SCHEMES.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl TaxIdSchemeInfo {
+    /// Normalize, and validate, the provided identifier, with or without
+    /// this scheme's prefix, returning `None` if it is not valid. Spaces and
+    /// punctuation are removed, and letters are upper-cased.
+    pub fn normalize(&self, tax_id: &str) -> Option<TaxId> {
+        let value = normalized(tax_id);
+        match &self.prefix {
+            Some(prefix) if value.starts_with(prefix.as_str()) => self
+                .parse_number(&value[prefix.len()..])
+                .or_else(|| self.parse_number(&value)),
+            _ => self.parse_number(&value),
+        }
+    }
+
+    /// Returns `true` if the provided identifier is valid for this scheme,
+    /// once normalized.
+    pub fn is_valid(&self, tax_id: &str) -> bool {
+        self.normalize(tax_id).is_some()
+    }
+
+    fn parse_number(&self, number: &str) -> Option<TaxId> {
+        if PATTERNS[&self.code].is_match(number) && self.checksum.is_valid(number) {
+            Some(TaxId {
+                scheme_code: self.code.to_string(),
+                country_code: self.country_code.to_string(),
+                subdivision_code: self.subdivision_code.clone(),
+                number: number.to_string(),
+                value: match &self.prefix {
+                    Some(prefix) => format!("{}{}", prefix, number),
+                    None => number.to_string(),
+                },
+                check_digit_verified: self.checksum.has_check_digit(number),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl TaxId {
+    /// Return the scheme this identifier was issued under, if it is known.
+    pub fn scheme(&self) -> Option<&'static TaxIdSchemeInfo> {
+        lookup(&self.scheme_code)
+    }
+
+    /// Return the issuing country, if it is known.
+    pub fn country(&self) -> Option<&'static CountryInfo> {
+        country::try_lookup(&self.country_code).ok()
+    }
+}
+
+impl TaxIdChecksum {
+    /// Returns `false` if the provided number, without prefix, is in a
+    /// format that has no check digit. The number must already match the
+    /// scheme's pattern.
+    pub(crate) fn has_check_digit(&self, number: &str) -> bool {
+        match self {
+            // birth numbers issued before 1954
+            TaxIdChecksum::Czechia => number.len() != 9 || number.starts_with('6'),
+            // personal codes issued since July 2017
+            TaxIdChecksum::Latvia => !number.starts_with("32"),
+            // government departments, and health authorities
+            TaxIdChecksum::UnitedKingdom => number.len() >= 9,
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if the check digits of the provided number, without
+    /// prefix, are correct, or the number has no check digit. The number
+    /// must already match the scheme's pattern.
+    pub(crate) fn is_valid(&self, number: &str) -> bool {
+        if !self.has_check_digit(number) {
+            return true;
+        }
+        let digits: Vec<u32> = number.chars().filter_map(|c| c.to_digit(10)).collect();
+        match self {
+            TaxIdChecksum::Australia => {
+                let mut digits = digits;
+                digits[0] -= 1;
+                weighted_sum(&digits, &[10, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19]) % 89 == 0
+            }
+            TaxIdChecksum::Austria => {
+                let sum: u32 = digits[..7]
+                    .iter()
+                    .enumerate()
+                    .map(|(index, digit)| {
+                        let product = digit * (1 + index as u32 % 2);
+                        product / 10 + product % 10
+                    })
+                    .sum();
+                (10 - (sum + 4) % 10) % 10 == digits[7]
+            }
+            TaxIdChecksum::Belgium => 97 - as_number(&digits[..8]) % 97 == as_number(&digits[8..]),
+            TaxIdChecksum::Bulgaria => {
+                if digits.len() == 9 {
+                    let mut check = weighted_sum(&digits, &[1, 2, 3, 4, 5, 6, 7, 8]) % 11;
+                    if check == 10 {
+                        check = weighted_sum(&digits, &[3, 4, 5, 6, 7, 8, 9, 10]) % 11 % 10;
+                    }
+                    check == digits[8]
+                } else {
+                    weighted_sum(&digits, &[2, 4, 8, 5, 10, 9, 7, 3, 6]) % 11 % 10 == digits[9]
+                }
+            }
+            TaxIdChecksum::Canada => luhn(&digits[..9]),
+            TaxIdChecksum::Cyprus => {
+                const ODD: [u32; 10] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21];
+                let sum: u32 = digits
+                    .iter()
+                    .enumerate()
+                    .map(|(index, digit)| {
+                        if index % 2 == 0 {
+                            ODD[*digit as usize]
+                        } else {
+                            *digit
+                        }
+                    })
+                    .sum();
+                number.ends_with((b'A' + (sum % 26) as u8) as char)
+            }
+            TaxIdChecksum::Czechia => match digits.len() {
+                8 => (11 - weighted_sum(&digits, &[8, 7, 6, 5, 4, 3, 2]) % 11) % 10 == digits[7],
+                10 => {
+                    as_number(&digits) % 11 == 0
+                        || (as_number(&digits[..9]) % 11 == 10 && digits[9] == 0)
+                }
+                _ => {
+                    // individuals without a birth number, the first digit is
+                    // not weighted
+                    let check = weighted_sum(&digits[1..], &[8, 7, 6, 5, 4, 3, 2]) % 11;
+                    (8 + check) % 10 == digits[8]
+                }
+            },
+            TaxIdChecksum::Denmark => weighted_sum(&digits, &[2, 7, 6, 5, 4, 3, 2, 1]) % 11 == 0,
+            TaxIdChecksum::Estonia => {
+                (10 - weighted_sum(&digits, &[3, 7, 1, 3, 7, 1, 3, 7]) % 10) % 10 == digits[8]
+            }
+            TaxIdChecksum::Finland => {
+                match 11 - weighted_sum(&digits, &[7, 9, 10, 5, 8, 4, 2]) % 11 {
+                    11 => digits[7] == 0,
+                    10 => false,
+                    check => check == digits[7],
+                }
+            }
+            TaxIdChecksum::France => {
+                if number[..2].chars().all(|c| c.is_ascii_digit()) {
+                    (12 + 3 * (as_number(&digits[2..]) % 97)) % 97 == as_number(&digits[..2])
+                } else {
+                    // the alphanumeric key, the letters I and O are not used
+                    const ALPHABET: &str = "0123456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+                    let key: Vec<u64> = number[..2]
+                        .chars()
+                        .filter_map(|c| ALPHABET.find(c).map(|index| index as u64))
+                        .collect();
+                    let siren = as_number(&digits[digits.len() - 9..]);
+                    let check = if key[0] < 10 {
+                        key[0] * 24 + key[1] - 10
+                    } else {
+                        key[0] * 34 + key[1] - 100
+                    };
+                    (siren + 1 + check / 11) % 11 == check % 11
+                }
+            }
+            TaxIdChecksum::Greece => {
+                weighted_sum(&digits, &[256, 128, 64, 32, 16, 8, 4, 2]) % 11 % 10 == digits[8]
+            }
+            TaxIdChecksum::Hungary => {
+                (10 - weighted_sum(&digits, &[9, 7, 3, 1, 9, 7, 3]) % 10) % 10 == digits[7]
+            }
+            TaxIdChecksum::India => {
+                let values: Vec<u32> = number.chars().filter_map(|c| c.to_digit(36)).collect();
+                let sum: u32 = values[..14]
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        let product = value * (1 + index as u32 % 2);
+                        product / 36 + product % 36
+                    })
+                    .sum();
+                (36 - sum % 36) % 36 == values[14]
+            }
+            TaxIdChecksum::Ireland => {
+                let chars: Vec<char> = number.chars().collect();
+                let (digits, check, extra): (Vec<u32>, char, Option<char>) =
+                    if chars[1].is_ascii_digit() {
+                        (
+                            chars[..7].iter().filter_map(|c| c.to_digit(10)).collect(),
+                            chars[7],
+                            chars.get(8).cloned(),
+                        )
+                    } else {
+                        let mut digits = vec![0];
+                        digits.extend(chars[2..7].iter().filter_map(|c| c.to_digit(10)));
+                        digits.push(chars[0].to_digit(10).unwrap());
+                        (digits, chars[7], None)
+                    };
+                let mut sum = weighted_sum(&digits, &[8, 7, 6, 5, 4, 3, 2]);
+                if let Some(extra) = extra {
+                    if extra != 'W' {
+                        sum += 9 * (extra as u32 - 'A' as u32 + 1);
+                    }
+                }
+                "WABCDEFGHIJKLMNOPQRSTUV".chars().nth((sum % 23) as usize) == Some(check)
+            }
+            TaxIdChecksum::Iso7064Mod11_10 => {
+                let mut product = 10;
+                for digit in &digits[..digits.len() - 1] {
+                    let sum = (product + digit) % 10;
+                    product = (if sum == 0 { 10 } else { sum }) * 2 % 11;
+                }
+                (11 - product) % 10 == digits[digits.len() - 1]
+            }
+            TaxIdChecksum::Latvia => {
+                if digits[0] <= 3 {
+                    // natural persons, the personal code
+                    let weights = [10, 5, 8, 4, 2, 1, 6, 3, 7, 9];
+                    (1 + weighted_sum(&digits, &weights)) % 11 % 10 == digits[10]
+                } else {
+                    let weights = [9, 1, 4, 8, 3, 10, 2, 5, 7, 6];
+                    match 3 - (weighted_sum(&digits, &weights) % 11) as i32 {
+                        -1 => false,
+                        check if check < -1 => check + 11 == digits[10] as i32,
+                        check => check == digits[10] as i32,
+                    }
+                }
+            }
+            TaxIdChecksum::Lithuania => {
+                let length = digits.len() - 1;
+                let weights: Vec<u32> = (0..length).map(|index| 1 + index as u32 % 9).collect();
+                let mut check = weighted_sum(&digits, &weights) % 11;
+                if check == 10 {
+                    let weights: Vec<u32> = (0..length)
+                        .map(|index| 1 + (index as u32 + 2) % 9)
+                        .collect();
+                    check = weighted_sum(&digits, &weights) % 11 % 10;
+                }
+                check == digits[length]
+            }
+            TaxIdChecksum::Luhn => luhn(&digits),
+            TaxIdChecksum::Luxembourg => as_number(&digits[..6]) % 89 == as_number(&digits[6..]),
+            TaxIdChecksum::Malta => {
+                (37 - weighted_sum(&digits, &[3, 4, 6, 7, 8, 9]) % 37) as u64
+                    == as_number(&digits[6..])
+            }
+            TaxIdChecksum::Netherlands => {
+                weighted_sum(&digits, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 == digits[8]
+                    || format!("NL{}", number)
+                        .chars()
+                        .filter_map(|c| c.to_digit(36))
+                        .fold(0, |remainder, value| {
+                            if value < 10 {
+                                (remainder * 10 + value) % 97
+                            } else {
+                                (remainder * 100 + value) % 97
+                            }
+                        })
+                        == 1
+            }
+            TaxIdChecksum::NewZealand => {
+                let mut digits = digits;
+                if digits.len() == 8 {
+                    digits.insert(0, 0);
+                }
+                match (11 - weighted_sum(&digits, &[3, 2, 7, 6, 5, 4, 3, 2]) % 11) % 11 {
+                    10 => match (11 - weighted_sum(&digits, &[7, 4, 3, 2, 5, 2, 7, 6]) % 11) % 11 {
+                        10 => false,
+                        check => check == digits[8],
+                    },
+                    check => check == digits[8],
+                }
+            }
+            TaxIdChecksum::Norway => modulus_11(&digits, &[3, 2, 7, 6, 5, 4, 3, 2]),
+            TaxIdChecksum::Poland => {
+                weighted_sum(&digits, &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == digits[9]
+            }
+            TaxIdChecksum::Portugal => {
+                let check = 11 - weighted_sum(&digits, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+                (if check >= 10 { 0 } else { check }) == digits[8]
+            }
+            TaxIdChecksum::Romania => {
+                let mut padded = vec![0; 10 - digits.len()];
+                padded.extend(&digits);
+                weighted_sum(&padded, &[7, 5, 3, 2, 1, 7, 5, 3, 2]) * 10 % 11 % 10 == padded[9]
+            }
+            TaxIdChecksum::Slovakia => as_number(&digits) % 11 == 0,
+            TaxIdChecksum::Slovenia => {
+                match 11 - weighted_sum(&digits, &[8, 7, 6, 5, 4, 3, 2]) % 11 {
+                    11 => false,
+                    10 => digits[7] == 0,
+                    check => check == digits[7],
+                }
+            }
+            TaxIdChecksum::Spain => {
+                const LETTERS: &str = "TRWAGMYFPDXBNJZSQVHLCKE";
+                let chars: Vec<char> = number.chars().collect();
+                let letter_for = |value: u64| LETTERS.chars().nth((value % 23) as usize);
+                match chars[0] {
+                    '0'..='9' => letter_for(as_number(&digits[..8])) == Some(chars[8]),
+                    'X' | 'Y' | 'Z' => {
+                        let mut value = vec![chars[0] as u32 - 'X' as u32];
+                        value.extend(&digits[..7]);
+                        letter_for(as_number(&value)) == Some(chars[8])
+                    }
+                    _ => {
+                        let sum: u32 = chars[1..8]
+                            .iter()
+                            .filter_map(|c| c.to_digit(10))
+                            .enumerate()
+                            .map(|(index, digit)| {
+                                if index % 2 == 0 {
+                                    (digit * 2) / 10 + (digit * 2) % 10
+                                } else {
+                                    digit
+                                }
+                            })
+                            .sum();
+                        let check = (10 - sum % 10) % 10;
+                        chars[8].to_digit(10) == Some(check)
+                            || "JABCDEFGHI".chars().nth(check as usize) == Some(chars[8])
+                    }
+                }
+            }
+            TaxIdChecksum::Sweden => luhn(&digits[..10]),
+            TaxIdChecksum::Switzerland => modulus_11(&digits, &[5, 4, 3, 2, 7, 6, 5, 4]),
+            TaxIdChecksum::UnitedKingdom => {
+                let sum =
+                    weighted_sum(&digits, &[8, 7, 6, 5, 4, 3, 2]) + as_number(&digits[7..9]) as u32;
+                sum % 97 == 0 || (sum + 55) % 97 == 0
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn normalized(tax_id: &str) -> String {
+    tax_id
+        .chars()
+        .filter(|c| !(c.is_whitespace() || ['.', '-', '/'].contains(c)))
+        .collect::<String>()
+        .to_uppercase()
+}
+
+fn weighted_sum(digits: &[u32], weights: &[u32]) -> u32 {
+    digits
+        .iter()
+        .zip(weights.iter())
+        .map(|(digit, weight)| digit * weight)
+        .sum()
+}
+
+fn as_number(digits: &[u32]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |number, digit| number * 10 + *digit as u64)
+}
+
+fn luhn(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                *digit
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+fn modulus_11(digits: &[u32], weights: &[u32]) -> bool {
+    let check_index = weights.len();
+    match 11 - weighted_sum(digits, weights) % 11 {
+        11 => digits[check_index] == 0,
+        10 => false,
+        check => check == digits[check_index],
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_tax_id_schemes_from_json() -> HashMap<String, TaxIdSchemeInfo> {
+    info!("load_tax_id_schemes_from_json - loading JSON");
+    let raw_data = include_bytes!("data/tax_ids.json");
+    let scheme_map: HashMap<String, TaxIdSchemeInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_tax_id_schemes_from_json - loaded {} tax identifier schemes",
+        scheme_map.len()
+    );
+    scheme_map
+}
+
+fn make_tax_id_patterns() -> HashMap<String, Regex> {
+    info!("make_tax_id_patterns - create from TAX_ID_SCHEMES");
+    let pattern_map: HashMap<String, Regex> = TAX_ID_SCHEMES
+        .values()
+        .map(|info| {
+            (
+                info.code.to_string(),
+                Regex::new(&format!("^(?:{})$", info.pattern)).unwrap(),
+            )
+        })
+        .collect();
+    info!(
+        "make_tax_id_patterns - compiled {} patterns",
+        pattern_map.len()
+    );
+    pattern_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_tax_id_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"DE-VAT".to_string()));
+        assert!(codes.contains(&"AU-ABN".to_string()));
+    }
+
+    #[test]
+    fn test_all_examples_valid() {
+        for code in all_codes() {
+            let scheme = lookup(&code).unwrap();
+            for example in &scheme.examples {
+                match scheme.normalize(example) {
+                    None => panic!("example {} for {} is not valid", example, code),
+                    Some(tax_id) => assert_eq!(&tax_id.number, example),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let germany = country::lookup("DEU").unwrap();
+        let tax_id = validate("de 136 695 976", germany).unwrap();
+        assert_eq!(tax_id.value, "DE136695976");
+        assert_eq!(tax_id.number, "136695976");
+        assert_eq!(
+            tax_id.scheme().unwrap().name,
+            "Umsatzsteuer-Identifikationsnummer"
+        );
+        assert!(validate("136695977", germany).is_none());
+
+        let australia = country::lookup("AU").unwrap();
+        let tax_id = validate("51 824 753 556", australia).unwrap();
+        assert_eq!(tax_id.scheme_code, "AU-ABN");
+        assert_eq!(tax_id.value, "51824753556");
+        assert!(validate("51 824 753 557", australia).is_none());
+
+        let switzerland = country::lookup("CH").unwrap();
+        let tax_id = validate("CHE-116.281.710 MWST", switzerland).unwrap();
+        assert_eq!(tax_id.value, "CHE116281710MWST");
+
+        let us = country::lookup("US").unwrap();
+        assert!(validate("123456789", us).is_none());
+    }
+
+    #[test]
+    fn test_validate_vat() {
+        let tax_id = validate_vat("EL094259216").unwrap();
+        assert_eq!(tax_id.country_code, "GR");
+        assert_eq!(tax_id.country().unwrap().code, "GRC");
+        assert!(validate_vat("GR094259216").is_none());
+
+        let tax_id = validate_vat("XI 980 7806 84").unwrap();
+        assert_eq!(tax_id.country_code, "GB");
+        assert_eq!(tax_id.subdivision_code, Some("GB-NIR".to_string()));
+        let tax_id = validate_vat("GB980780684").unwrap();
+        assert_eq!(tax_id.subdivision_code, None);
+
+        assert_eq!(validate_vat("esx5253868r").unwrap().value, "ESX5253868R");
+
+        assert!(validate_vat("FRK7399859412").unwrap().check_digit_verified);
+        assert!(validate_vat("FRK8399859412").is_none());
+        assert!(validate_vat("LV16117519997").unwrap().check_digit_verified);
+        assert!(validate_vat("LV16117519996").is_none());
+        assert!(!validate_vat("LV32117519996").unwrap().check_digit_verified);
+        assert!(validate_vat("CZ600010015").unwrap().check_digit_verified);
+        assert!(validate_vat("CZ600010016").is_none());
+        assert!(validate_vat("CZ600000008").unwrap().check_digit_verified);
+        assert!(!validate_vat("CZ530101123").unwrap().check_digit_verified);
+        assert!(!validate_vat("GBGD001").unwrap().check_digit_verified);
+        assert!(validate_vat("GB980780684").unwrap().check_digit_verified);
+        assert!(validate_vat("980780684").is_none());
+        assert!(validate_vat("").is_none());
+    }
+
+    #[test]
+    fn test_country_for_prefix() {
+        assert_eq!(country_for_prefix("EL").unwrap().short_code, "GR");
+        assert_eq!(country_for_prefix("XI").unwrap().short_code, "GB");
+        assert_eq!(country_for_prefix("DE").unwrap().short_code, "DE");
+        assert!(country_for_prefix("GR").is_none());
+    }
+
+    #[test]
+    fn test_schemes_for_country() {
+        let uk = country::lookup("GBR").unwrap();
+        let codes: Vec<&str> = schemes_for_country(uk)
+            .iter()
+            .map(|scheme| scheme.code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["GB-VAT", "GB-NIR-VAT"]);
+        let tax_id = validate("980780684", uk).unwrap();
+        assert_eq!(tax_id.value, "GB980780684");
+        let tax_id = validate("XI980780684", uk).unwrap();
+        assert_eq!(tax_id.scheme_code, "GB-NIR-VAT");
+    }
+
+    #[test]
+    fn test_try_tax_id_lookup() {
+        assert_eq!(try_lookup("AU-ABN").unwrap().code, "AU-ABN");
+        assert_eq!(
+            try_lookup("").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        let error = try_lookup("au-abn").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert_eq!(error.suggestions()[0], "AU-ABN");
+        assert!(try_lookup("XX-XYZ-ABC")
+            .unwrap_err()
+            .suggestions()
+            .is_empty());
+    }
+}
@@ -0,0 +1,248 @@
+/*!
+Languages used within countries, and their official status.
+
+The Unicode Common Locale Data Repository (CLDR) records, for each territory,
+it's population and the languages used within it. Each language is recorded
+with the percentage of the population that use it, as a first or second
+language, and whether it has any official status. This can be used to select
+a default language for a country, or to order the languages offered to users
+in a particular country.
+
+## Source - CLDR
+
+The data used here is taken from the `territoryInfo` section of the CLDR
+[supplemental data](https://github.com/unicode-org/cldr/blob/main/common/supplemental/supplementalData.xml).
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::{self, CountryInfo};
+use crate::error::{check_alphabetic, LookupError};
+use crate::language::{self, LanguageInfo};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The official status of a language within a country.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OfficialStatus {
+    /// An official language of the country.
+    Official,
+    /// Not legally an official language, but used as one, for example
+    /// English in the United States.
+    DeFactoOfficial,
+    /// An official language in some region of the country only.
+    OfficialRegional,
+    /// Used within the country, but with no official status.
+    Unofficial,
+}
+
+/// The use of a single language within a country.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LanguageUsage {
+    /// The ISO-639 2-character, or 3-character, identifier of the language.
+    pub language_code: String,
+    /// The percentage of the country's population using the language.
+    pub population_percent: f32,
+    /// The official status of the language within the country.
+    pub status: OfficialStatus,
+}
+
+/// A representation of the territory data for a country maintained by CLDR.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TerritoryInfo {
+    /// The ISO-3166, part 1, 2-character identifier of the country.
+    pub country_code: String,
+    /// The approximate population of the country.
+    pub population: u64,
+    /// The languages used within the country, ordered from the most to the
+    /// least widely used.
+    pub languages: Vec<LanguageUsage>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref TERRITORIES: HashMap<String, TerritoryInfo> = load_territories_from_json();
+}
+
+/// Lookup a `TerritoryInfo` based on the country's ISO-3166 2-character
+/// identifier, returning `None` if the country has no territory data.
+pub fn lookup(code: &str) -> Option<&'static TerritoryInfo> {
+    debug!("territory::lookup: {}", code);
+    TERRITORIES.get(code)
+}
+
+/// Lookup a `TerritoryInfo` based on the country's ISO-3166 2-character
+/// identifier, returning a `LookupError` rather than `None` if the code is
+/// malformed or unknown.
+pub fn try_lookup(code: &str) -> Result<&'static TerritoryInfo, LookupError> {
+    debug!("territory::try_lookup: {}", code);
+    check_alphabetic(
+        code,
+        &[2],
+        |code| code.to_ascii_uppercase(),
+        |code| lookup(code).is_some(),
+    )?;
+    match lookup(code) {
+        Some(info) => Ok(info),
+        None => Err(LookupError::unknown(
+            code,
+            TERRITORIES.keys().map(|k| k.as_str()),
+        )),
+    }
+}
+
+/// Return the languages used within the provided country, ordered from the
+/// most to the least widely used.
+pub fn languages_for(country: &CountryInfo) -> Vec<&'static LanguageUsage> {
+    match lookup(&country.short_code) {
+        Some(territory) => territory.languages.iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Return the official, and de facto official, languages of the provided
+/// country, ordered from the most to the least widely used.
+pub fn official_languages(country: &CountryInfo) -> Vec<&'static LanguageInfo> {
+    languages_for(country)
+        .iter()
+        .filter(|usage| {
+            usage.status == OfficialStatus::Official
+                || usage.status == OfficialStatus::DeFactoOfficial
+        })
+        .filter_map(|usage| language::lookup(&usage.language_code))
+        .collect()
+}
+
+/// Return the countries in which the provided language is used, along with
+/// the approximate number of people using it in each, ordered from the
+/// largest to the smallest number of speakers.
+pub fn countries_speaking(language: &LanguageInfo) -> Vec<(&'static CountryInfo, u64)> {
+    let mut countries: Vec<(&'static CountryInfo, u64)> = TERRITORIES
+        .values()
+        .filter_map(|territory| {
+            territory
+                .languages
+                .iter()
+                .find(|usage| is_language(&usage.language_code, language))
+                .map(|usage| (territory, usage))
+        })
+        .filter_map(|(territory, usage)| {
+            country::lookup(&territory.country_code).map(|country| {
+                let speakers = territory.population as f64 * usage.population_percent as f64;
+                (country, (speakers / 100.0).round() as u64)
+            })
+        })
+        .collect();
+    countries.sort_by(|lhs, rhs| rhs.1.cmp(&lhs.1).then(lhs.0.code.cmp(&rhs.0.code)));
+    countries
+}
+
+/// Return all the ISO-3166 2-character country codes with territory data.
+pub fn all_codes() -> Vec<String> {
+    TERRITORIES.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_language(code: &str, language: &LanguageInfo) -> bool {
+    code == language.code || Some(code) == language.short_code.as_ref().map(String::as_str)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_territories_from_json() -> HashMap<String, TerritoryInfo> {
+    info!("load_territories_from_json - loading JSON");
+    let raw_data = include_bytes!("data/territories.json");
+    let territory_map: HashMap<String, TerritoryInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_territories_from_json - loaded {} territories",
+        territory_map.len()
+    );
+    territory_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::LookupErrorKind;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_territory_codes() {
+        let codes = all_codes();
+        assert!(!codes.is_empty());
+    }
+
+    #[test]
+    fn test_languages_for_country() {
+        let switzerland = country::lookup("CHE").unwrap();
+        let languages = languages_for(switzerland);
+        assert_eq!(languages[0].language_code, "de");
+        assert_eq!(languages[0].status, OfficialStatus::Official);
+
+        let codes: Vec<&str> = official_languages(switzerland)
+            .iter()
+            .map(|l| l.code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["deu", "fra", "ita", "roh"]);
+    }
+
+    #[test]
+    fn test_de_facto_official_language() {
+        let usa = country::lookup("USA").unwrap();
+        let languages = languages_for(usa);
+        assert_eq!(languages[0].status, OfficialStatus::DeFactoOfficial);
+        assert_eq!(official_languages(usa)[0].code, "eng");
+    }
+
+    #[test]
+    fn test_countries_speaking() {
+        let german = language::lookup("de").unwrap();
+        let countries = countries_speaking(german);
+        assert_eq!(countries[0].0.code, "DEU");
+        assert!(countries.iter().any(|(c, _)| c.code == "AUT"));
+        assert!(countries.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    #[test]
+    fn test_bad_territory_code() {
+        match lookup("XX") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+    }
+
+    #[test]
+    fn test_try_territory_lookup() {
+        assert!(try_lookup("DE").is_ok());
+        assert_eq!(
+            try_lookup("de").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("D").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        assert_eq!(
+            try_lookup("QQ").unwrap_err().kind(),
+            LookupErrorKind::Unknown
+        );
+    }
+}
@@ -0,0 +1,394 @@
+/*!
+Time zones, and the countries that use them.
+
+The IANA Time Zone Database identifies each time zone by a name of the form
+`Area/Location`, for example `Europe/Berlin`. Zones whose clocks have agreed
+since 1970 are merged into a single _canonical_ zone, and the other names are
+kept as _aliases_ (links in IANA terms) that resolve to it; for example
+`Europe/Oslo` is an alias of `Europe/Berlin`, and the backward compatible
+name `US/Eastern` is an alias of `America/New_York`.
+
+Each country-specific zone name is associated with the country it is named
+for, and with the location it is named after. The names returned by
+`zones_for_country` are those a user would expect to choose from; canonical
+zones also record all the countries that share them.
+
+Microsoft Windows uses a different set of time zone identifiers, such as
+`W. Europe Standard Time`, these can be mapped to and from IANA names with
+`windows_to_iana` and `iana_to_windows`.
+
+## Source - IANA
+
+The data used here is taken from the IANA
+[Time Zone Database](https://www.iana.org/time-zones), the Windows mapping
+was compiled from the CLDR
+[windowsZones](https://github.com/unicode-org/cldr/blob/main/common/supplemental/windowsZones.xml)
+supplemental data.
+*/
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::CountryInfo;
+use crate::error::{LookupError, LookupErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// A location, in degrees, as recorded in ISO 6709 format by IANA.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    /// The latitude, positive north of the equator.
+    pub latitude: f64,
+    /// The longitude, positive east of Greenwich.
+    pub longitude: f64,
+}
+
+/// A representation of a time zone maintained by IANA.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TimeZoneInfo {
+    /// The IANA identifier of the zone, for example `Europe/Berlin`.
+    pub id: String,
+    /// The ISO-3166, part 1, 2-character identifier of the country this zone
+    /// is named for, if any; zones such as `Etc/UTC` have no country.
+    pub country_code: Option<String>,
+    /// The ISO-3166, part 1, 2-character identifiers of all the countries
+    /// whose clocks have agreed with this zone since 1970.
+    pub country_codes: Vec<String>,
+    /// The location of the principal city of the zone, if any.
+    pub coordinates: Option<Coordinates>,
+    /// A comment distinguishing zones within the same country, for example
+    /// "most of Germany".
+    pub comment: Option<String>,
+    /// The canonical identifier of this zone, if this identifier is an alias.
+    pub alias_of: Option<String>,
+}
+
+/// A mapping from a Windows time zone identifier to IANA zone identifiers
+/// for a single territory.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WindowsZoneInfo {
+    /// The Windows identifier of the zone, for example `W. Europe Standard Time`.
+    pub windows_id: String,
+    /// The ISO-3166, part 1, 2-character identifier of the country, or `001`
+    /// for the default mapping of the Windows identifier.
+    pub territory: String,
+    /// The IANA identifiers of the zone within the territory, the first being
+    /// preferred.
+    pub zone_ids: Vec<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref TIMEZONES: HashMap<String, TimeZoneInfo> = load_timezones_from_json();
+    static ref LINKS: HashMap<String, String> = load_timezone_links_from_json();
+    static ref WINDOWS_ZONES: Vec<WindowsZoneInfo> = load_windows_zones_from_json();
+}
+
+/// Lookup a `TimeZoneInfo` based on it's IANA identifier, returning `None`
+/// if the identifier is unknown. Aliases that are not themselves named for a
+/// country, such as `US/Eastern`, return the canonical zone.
+pub fn lookup(id: &str) -> Option<&'static TimeZoneInfo> {
+    debug!("timezone::lookup: {}", id);
+    match TIMEZONES.get(id) {
+        Some(zone) => Some(zone),
+        None => match LINKS.get(id) {
+            Some(canonical) => TIMEZONES.get(canonical),
+            None => None,
+        },
+    }
+}
+
+/// Lookup a `TimeZoneInfo` based on it's IANA identifier, returning a
+/// `LookupError` rather than `None` if the identifier is malformed or
+/// unknown.
+pub fn try_lookup(id: &str) -> Result<&'static TimeZoneInfo, LookupError> {
+    debug!("timezone::try_lookup: {}", id);
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/_+-".contains(c))
+    {
+        return Err(LookupError::new(LookupErrorKind::Malformed, id, Vec::new()));
+    }
+    match lookup(id) {
+        Some(zone) => Ok(zone),
+        None => Err(LookupError::unknown(
+            id,
+            TIMEZONES.keys().chain(LINKS.keys()).map(|k| k.as_str()),
+        )),
+    }
+}
+
+/// Return the canonical IANA identifier for the provided identifier, which
+/// will be the identifier itself if it is not an alias, returning `None` if
+/// the identifier is unknown.
+pub fn canonical_id(id: &str) -> Option<&'static str> {
+    match LINKS.get(id) {
+        Some(canonical) => Some(canonical.as_str()),
+        None => TIMEZONES.get(id).map(|zone| zone.id.as_str()),
+    }
+}
+
+/// Returns `true` if the identifier is a canonical IANA zone, and not an alias.
+pub fn is_canonical(id: &str) -> bool {
+    canonical_id(id) == Some(id)
+}
+
+/// Return all the aliases of the provided identifier's canonical zone,
+/// sorted by identifier.
+pub fn aliases(id: &str) -> Vec<&'static str> {
+    match canonical_id(id) {
+        Some(canonical) => {
+            let mut aliases: Vec<&'static str> = LINKS
+                .iter()
+                .filter(|(_, target)| target.as_str() == canonical)
+                .map(|(alias, _)| alias.as_str())
+                .collect();
+            aliases.sort_unstable();
+            aliases
+        }
+        None => Vec::new(),
+    }
+}
+
+/// Return the zones named for the provided country, sorted by identifier.
+/// Some of these may be aliases of a zone in a neighbouring country, for
+/// example `Europe/Oslo`.
+pub fn zones_for_country(country: &CountryInfo) -> Vec<&'static TimeZoneInfo> {
+    let mut zones: Vec<&'static TimeZoneInfo> = TIMEZONES
+        .values()
+        .filter(|zone| zone.country_code.as_ref() == Some(&country.short_code))
+        .collect();
+    zones.sort_by(|lhs, rhs| lhs.id.cmp(&rhs.id));
+    zones
+}
+
+/// Return the preferred IANA zone for the Windows time zone identifier. If a
+/// country is provided, and the Windows zone has a specific mapping for it,
+/// that is used in place of the default.
+pub fn windows_to_iana(
+    windows_id: &str,
+    country: Option<&CountryInfo>,
+) -> Option<&'static TimeZoneInfo> {
+    debug!("timezone::windows_to_iana: {}", windows_id);
+    let for_territory = |territory: &str| {
+        WINDOWS_ZONES
+            .iter()
+            .find(|info| info.windows_id == windows_id && info.territory == territory)
+    };
+    let mapping = match country.and_then(|country| for_territory(&country.short_code)) {
+        Some(mapping) => Some(mapping),
+        None => for_territory(DEFAULT_TERRITORY),
+    };
+    mapping
+        .and_then(|info| info.zone_ids.first())
+        .and_then(|id| lookup(id))
+}
+
+/// Return the Windows time zone identifier for the IANA zone identifier,
+/// returning `None` if the zone is unknown or has no Windows mapping.
+pub fn iana_to_windows(id: &str) -> Option<&'static str> {
+    debug!("timezone::iana_to_windows: {}", id);
+    let find = |id: &str| {
+        WINDOWS_ZONES
+            .iter()
+            .find(|info| info.zone_ids.iter().any(|zone_id| zone_id == id))
+            .map(|info| info.windows_id.as_str())
+    };
+    match find(id) {
+        Some(windows_id) => Some(windows_id),
+        None => match canonical_id(id) {
+            Some(canonical) => WINDOWS_ZONES
+                .iter()
+                .find(|info| {
+                    info.zone_ids
+                        .iter()
+                        .any(|zone_id| canonical_id(zone_id) == Some(canonical))
+                })
+                .map(|info| info.windows_id.as_str()),
+            None => None,
+        },
+    }
+}
+
+/// Return all the Windows time zone identifiers with a mapping.
+pub fn all_windows_ids() -> Vec<String> {
+    let mut ids: Vec<String> = WINDOWS_ZONES
+        .iter()
+        .map(|info| info.windows_id.to_string())
+        .collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// Return all the IANA zone identifiers, including aliases.
+pub fn all_codes() -> Vec<String> {
+    TIMEZONES
+        .keys()
+        .chain(LINKS.keys().filter(|alias| !TIMEZONES.contains_key(*alias)))
+        .cloned()
+        .collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+const DEFAULT_TERRITORY: &str = "001";
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_timezones_from_json() -> HashMap<String, TimeZoneInfo> {
+    info!("load_timezones_from_json - loading JSON");
+    let raw_data = include_bytes!("data/timezones.json");
+    let timezone_map: HashMap<String, TimeZoneInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_timezones_from_json - loaded {} time zones",
+        timezone_map.len()
+    );
+    timezone_map
+}
+
+fn load_timezone_links_from_json() -> HashMap<String, String> {
+    info!("load_timezone_links_from_json - loading JSON");
+    let raw_data = include_bytes!("data/timezone_links.json");
+    let link_map: HashMap<String, String> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_timezone_links_from_json - loaded {} links",
+        link_map.len()
+    );
+    link_map
+}
+
+fn load_windows_zones_from_json() -> Vec<WindowsZoneInfo> {
+    info!("load_windows_zones_from_json - loading JSON");
+    let raw_data = include_bytes!("data/windows_zones.json");
+    let windows_zones: Vec<WindowsZoneInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!(
+        "load_windows_zones_from_json - loaded {} mappings",
+        windows_zones.len()
+    );
+    windows_zones
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::country;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_timezone_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"Europe/Berlin".to_string()));
+        assert!(codes.contains(&"US/Eastern".to_string()));
+    }
+
+    #[test]
+    fn test_good_timezone() {
+        match lookup("Europe/Berlin") {
+            None => panic!("was expecting a time zone"),
+            Some(zone) => {
+                assert_eq!(zone.country_code, Some("DE".to_string()));
+                assert!(zone.country_codes.contains(&"NO".to_string()));
+                let coordinates = zone.coordinates.unwrap();
+                assert!((coordinates.latitude - 52.5).abs() < 0.01);
+                assert!((coordinates.longitude - 13.3667).abs() < 0.01);
+                assert_eq!(zone.alias_of, None);
+            }
+        }
+        assert!(lookup("Etc/UTC").unwrap().coordinates.is_none());
+    }
+
+    #[test]
+    fn test_bad_timezone() {
+        match lookup("Europe/Nowhere") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+        assert!(canonical_id("Europe/Nowhere").is_none());
+    }
+
+    #[test]
+    fn test_canonical_and_aliases() {
+        assert!(is_canonical("Europe/Berlin"));
+        assert!(!is_canonical("Europe/Oslo"));
+        assert_eq!(canonical_id("Europe/Oslo"), Some("Europe/Berlin"));
+        assert_eq!(canonical_id("US/Eastern"), Some("America/New_York"));
+        assert_eq!(lookup("US/Eastern").unwrap().id, "America/New_York");
+        assert_eq!(
+            lookup("Europe/Oslo").unwrap().alias_of,
+            Some("Europe/Berlin".to_string())
+        );
+        assert!(aliases("America/New_York").contains(&"US/Eastern"));
+        assert!(aliases("Europe/Oslo").contains(&"Europe/Oslo"));
+    }
+
+    #[test]
+    fn test_zones_for_country() {
+        let norway = country::lookup("NO").unwrap();
+        let ids: Vec<&str> = zones_for_country(norway)
+            .iter()
+            .map(|zone| zone.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["Europe/Oslo"]);
+
+        let australia = country::lookup("AUS").unwrap();
+        let zones = zones_for_country(australia);
+        assert!(zones.len() > 1);
+        assert!(zones.iter().any(|zone| zone.id == "Australia/Sydney"));
+    }
+
+    #[test]
+    fn test_windows_mapping() {
+        assert_eq!(
+            windows_to_iana("W. Europe Standard Time", None).unwrap().id,
+            "Europe/Berlin"
+        );
+        let sweden = country::lookup("SE").unwrap();
+        assert_eq!(
+            windows_to_iana("W. Europe Standard Time", Some(sweden))
+                .unwrap()
+                .id,
+            "Europe/Stockholm"
+        );
+        assert!(windows_to_iana("Mars Standard Time", None).is_none());
+
+        assert_eq!(
+            iana_to_windows("America/New_York"),
+            Some("Eastern Standard Time")
+        );
+        assert_eq!(iana_to_windows("US/Pacific"), Some("Pacific Standard Time"));
+        assert!(iana_to_windows("Europe/Nowhere").is_none());
+
+        for windows_id in all_windows_ids() {
+            assert!(windows_to_iana(&windows_id, None).is_some());
+        }
+    }
+
+    #[test]
+    fn test_try_timezone_lookup() {
+        assert_eq!(try_lookup("Europe/London").unwrap().id, "Europe/London");
+        assert_eq!(
+            try_lookup("Europe London").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        let error = try_lookup("Europe/Londn").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert_eq!(error.suggestions()[0], "Europe/London");
+    }
+}
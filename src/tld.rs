@@ -0,0 +1,299 @@
+/*!
+Top-level domains, and the countries they are delegated to.
+
+The Internet Assigned Numbers Authority (IANA) delegates country code
+top-level domains (ccTLDs) based on the ISO 3166-1 2-character country codes,
+for example `.de` for Germany. There are a few well known exceptions; the
+United Kingdom uses `.uk` (although `.gb` is also delegated), Ascension Island
+uses `.ac` as a part of Saint Helena, `.eu` is delegated to the European Union
+as a regional domain, and `.su` remains in use although the Soviet Union no
+longer exists. Many countries have also been delegated internationalized
+ccTLDs in their own scripts, such as `.рф` for Russia.
+
+Domains are accepted with or without a leading `.`, and in Unicode or ASCII
+(Punycode) form; only the last label is considered, so `www.example.co.uk`,
+`.co.uk`, and `uk` all resolve to the `.uk` ccTLD. The `public_suffix`
+function can be used to find the complete suffix under which names are
+registered, `co.uk` in the example above.
+
+## Source - Public Suffix List
+
+The data used here is taken from the ICANN section of the Mozilla
+[Public Suffix List](https://publicsuffix.org/list/), with the exceptions
+above added by hand.
+*/
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::country::CountryInfo;
+use crate::error::{LookupError, LookupErrorKind};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The kind of a top-level domain.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TldKind {
+    /// A country code top-level domain, such as `.de` or `.рф`.
+    CountryCode,
+    /// A generic top-level domain, such as `.com` or `.museum`.
+    Generic,
+    /// A top-level domain delegated to a region spanning several countries,
+    /// such as `.eu` or `.asia`.
+    Regional,
+}
+
+/// A representation of a top-level domain delegated by IANA.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TldInfo {
+    /// The top-level domain, without the leading `.`, in Unicode form; for
+    /// example `uk` or `рф`. This is the primary identifier.
+    pub tld: String,
+    /// The top-level domain in ASCII form, this is the Punycode encoding for
+    /// internationalized domains, for example `xn--p1ai`.
+    pub ascii_tld: String,
+    /// The kind of the top-level domain.
+    pub kind: TldKind,
+    /// The ISO-3166, part 1, 2-character identifier of the country the
+    /// domain is delegated for, if any.
+    pub country_code: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref TLDS: HashMap<String, TldInfo> = load_tlds_from_json();
+    static ref ASCII_LOOKUP: HashMap<String, String> = make_tld_ascii_lookup();
+    static ref SUFFIX_RULES: HashSet<String> = load_suffix_rules_from_json();
+}
+
+/// Lookup a `TldInfo` for the top-level domain of the provided domain name,
+/// returning `None` if the domain is not delegated.
+pub fn lookup(domain: &str) -> Option<&'static TldInfo> {
+    debug!("tld::lookup: {}", domain);
+    let tld = top_level_label(domain);
+    match TLDS.get(&tld) {
+        Some(info) => Some(info),
+        None => match ASCII_LOOKUP.get(&tld) {
+            Some(tld) => TLDS.get(tld),
+            None => None,
+        },
+    }
+}
+
+/// Lookup a `TldInfo` for the top-level domain of the provided domain name,
+/// returning a `LookupError` rather than `None` if the domain is empty or
+/// it's top-level domain is not delegated.
+pub fn try_lookup(domain: &str) -> Result<&'static TldInfo, LookupError> {
+    debug!("tld::try_lookup: {}", domain);
+    let tld = top_level_label(domain);
+    if tld.is_empty() || tld.contains(char::is_whitespace) {
+        return Err(LookupError::new(
+            LookupErrorKind::Malformed,
+            domain,
+            Vec::new(),
+        ));
+    }
+    match lookup(&tld) {
+        Some(info) => Ok(info),
+        None => Err(LookupError::unknown(&tld, TLDS.keys().map(|k| k.as_str()))),
+    }
+}
+
+/// Return the public suffix of the provided domain name, that is the
+/// longest suffix under which names may be registered according to the
+/// ICANN section of the Public Suffix List; for example `co.uk` for
+/// `www.example.co.uk`, or `рф` for `пример.рф`. The suffix is returned in
+/// lower case, in the same (Unicode or ASCII) form as the domain, and
+/// `None` is returned if the top-level domain is not delegated.
+pub fn public_suffix(domain: &str) -> Option<String> {
+    debug!("tld::public_suffix: {}", domain);
+    lookup(domain)?;
+    let domain = domain
+        .trim()
+        .trim_start_matches('.')
+        .trim_end_matches('.')
+        .to_lowercase();
+    let labels: Vec<&str> = domain.split('.').collect();
+    for start in 0..labels.len() {
+        let suffix = labels[start..].join(".");
+        if SUFFIX_RULES.contains(&format!("!{}", suffix)) {
+            return Some(labels[start + 1..].join("."));
+        }
+        if SUFFIX_RULES.contains(&suffix) {
+            return Some(suffix);
+        }
+        if start + 1 < labels.len()
+            && SUFFIX_RULES.contains(&format!("*.{}", labels[start + 1..].join(".")))
+        {
+            return Some(suffix);
+        }
+    }
+    labels.last().map(|label| label.to_string())
+}
+
+/// Return the top-level domains delegated for the provided country, ASCII
+/// domains first, then internationalized domains, each sorted by domain.
+pub fn tlds_for_country(country: &CountryInfo) -> Vec<&'static TldInfo> {
+    let mut tlds: Vec<&'static TldInfo> = TLDS
+        .values()
+        .filter(|info| info.country_code.as_ref() == Some(&country.short_code))
+        .collect();
+    tlds.sort_by(|lhs, rhs| (!lhs.tld.is_ascii(), &lhs.tld).cmp(&(!rhs.tld.is_ascii(), &rhs.tld)));
+    tlds
+}
+
+/// Return all the delegated top-level domains, in Unicode form.
+pub fn all_codes() -> Vec<String> {
+    TLDS.keys().cloned().collect()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn top_level_label(domain: &str) -> String {
+    domain
+        .trim()
+        .trim_end_matches('.')
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Generated Data
+// ------------------------------------------------------------------------------------------------
+
+fn load_tlds_from_json() -> HashMap<String, TldInfo> {
+    info!("load_tlds_from_json - loading JSON");
+    let raw_data = include_bytes!("data/tlds.json");
+    let tld_map: HashMap<String, TldInfo> = serde_json::from_slice(raw_data).unwrap();
+    info!("load_tlds_from_json - loaded {} domains", tld_map.len());
+    tld_map
+}
+
+fn load_suffix_rules_from_json() -> HashSet<String> {
+    info!("load_suffix_rules_from_json - loading JSON");
+    let raw_data = include_bytes!("data/public_suffixes.json");
+    let rules: HashSet<String> = serde_json::from_slice(raw_data).unwrap();
+    info!("load_suffix_rules_from_json - loaded {} rules", rules.len());
+    rules
+}
+
+fn make_tld_ascii_lookup() -> HashMap<String, String> {
+    info!("make_tld_ascii_lookup - create from TLDS");
+    let mut lookup_map: HashMap<String, String> = HashMap::new();
+    for info in TLDS.values().filter(|info| info.ascii_tld != info.tld) {
+        lookup_map.insert(info.ascii_tld.to_string(), info.tld.to_string());
+    }
+    info!(
+        "make_tld_ascii_lookup - mapped {} internationalized domains",
+        lookup_map.len()
+    );
+    lookup_map
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::country;
+
+    // --------------------------------------------------------------------------------------------
+    #[test]
+    fn test_tld_codes() {
+        let codes = all_codes();
+        assert!(codes.contains(&"com".to_string()));
+        assert!(codes.contains(&"рф".to_string()));
+    }
+
+    #[test]
+    fn test_good_tld() {
+        for domain in &["de", ".de", "www.example.de.", "EXAMPLE.DE"] {
+            match lookup(domain) {
+                None => panic!("was expecting a top-level domain"),
+                Some(info) => {
+                    assert_eq!(info.tld, "de");
+                    assert_eq!(info.kind, TldKind::CountryCode);
+                    assert_eq!(info.country_code, Some("DE".to_string()));
+                }
+            }
+        }
+        assert_eq!(lookup("com").unwrap().kind, TldKind::Generic);
+        assert_eq!(lookup(".eu").unwrap().kind, TldKind::Regional);
+        assert_eq!(lookup(".eu").unwrap().country_code, None);
+    }
+
+    #[test]
+    fn test_internationalized_tld() {
+        let info = lookup("пример.рф").unwrap();
+        assert_eq!(info.ascii_tld, "xn--p1ai");
+        assert_eq!(info.country_code, Some("RU".to_string()));
+        assert_eq!(lookup("xn--80aswg.xn--p1ai").unwrap().tld, "рф");
+    }
+
+    #[test]
+    fn test_bad_tld() {
+        match lookup("example.invalid") {
+            None => (),
+            Some(_) => panic!("was expecting a None in response"),
+        }
+        assert!(lookup("").is_none());
+    }
+
+    #[test]
+    fn test_public_suffix() {
+        assert_eq!(
+            public_suffix("www.example.co.uk"),
+            Some("co.uk".to_string())
+        );
+        assert_eq!(public_suffix(".CO.UK."), Some("co.uk".to_string()));
+        assert_eq!(public_suffix("example.de"), Some("de".to_string()));
+        assert_eq!(public_suffix("пример.рф"), Some("рф".to_string()));
+        assert_eq!(public_suffix("example.ck"), Some("example.ck".to_string()));
+        assert_eq!(public_suffix("www.ck"), Some("ck".to_string()));
+        assert_eq!(public_suffix("example.invalid"), None);
+    }
+
+    #[test]
+    fn test_tlds_for_country() {
+        let uk = country::lookup("GBR").unwrap();
+        let tlds: Vec<&str> = tlds_for_country(uk)
+            .iter()
+            .map(|info| info.tld.as_str())
+            .collect();
+        assert_eq!(tlds, vec!["gb", "uk"]);
+
+        let russia = country::lookup("RUS").unwrap();
+        let tlds: Vec<&str> = tlds_for_country(russia)
+            .iter()
+            .map(|info| info.tld.as_str())
+            .collect();
+        assert_eq!(tlds, vec!["ru", "рф"]);
+    }
+
+    #[test]
+    fn test_try_tld_lookup() {
+        assert_eq!(try_lookup("example.de").unwrap().tld, "de");
+        assert_eq!(
+            try_lookup(" . ").unwrap_err().kind(),
+            LookupErrorKind::Malformed
+        );
+        let error = try_lookup("example.dx").unwrap_err();
+        assert_eq!(error.kind(), LookupErrorKind::Unknown);
+        assert_eq!(error.code(), "dx");
+        assert!(error.suggestions().contains(&"de".to_string()));
+    }
+}